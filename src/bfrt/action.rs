@@ -25,6 +25,7 @@ use serde::Deserialize;
 #[derive(Deserialize, Debug, Clone)]
 enum ActionScope {
     TableAndDefault,
+    TableOnly,
     DefaultOnly,
 }
 
@@ -57,18 +58,31 @@ impl ActionData {
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    pub fn get_type(&self) -> Option<&BFRTFieldType> {
+        self.r#type.as_ref()
+    }
 }
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct BFRTAction {
     pub(crate) id: u32,
     pub(crate) name: String,
-    #[allow(dead_code)]
     action_scope: Option<ActionScope>,
     data: Option<Vec<ActionData>>,
 }
 
 impl BFRTAction {
+    /// Returns whether the action may be used as the default action of a table.
+    pub fn is_default_allowed(&self) -> bool {
+        !matches!(self.action_scope, Some(ActionScope::TableOnly))
+    }
+
+    /// Returns the parameters of the action.
+    pub fn get_action_data(&self) -> &[ActionData] {
+        self.data.as_deref().unwrap_or_default()
+    }
+
     pub fn get_action_data_by_id(&self, id: u32) -> Result<&ActionData, RBFRTError> {
         if let Some(data) = &self.data {
             for d in data {
@@ -19,9 +19,10 @@
 
 use serde::Deserialize;
 
-use crate::bfrt::{BFRTTableObject, LearnFilterObject};
+use crate::bfrt::{BFRTTableObject, LearnFilterObject, TableType};
 use crate::error::RBFRTError;
 use crate::error::RBFRTError::{ConvertError, UnknownLearnFilter, UnknownTable, UnknownTableId};
+use crate::object::ObjectName;
 
 /// a struct into which to decode the thing
 #[derive(Deserialize, Debug, Clone)]
@@ -53,14 +54,40 @@ impl BFRTInfo {
         Err(UnknownTableId { table_id: id })
     }
 
+    /// Returns the first table of the given `table_type`.
+    pub fn table_get_by_type(&self, table_type: &TableType) -> Option<&BFRTTableObject> {
+        self.tables
+            .iter()
+            .find(|t| t.get_table_type() == table_type)
+    }
+
     pub fn tables(self) -> Vec<BFRTTableObject> {
         self.tables
     }
 
+    pub fn get_tables(&self) -> &Vec<BFRTTableObject> {
+        &self.tables
+    }
+
     pub fn add_table(&mut self, table: BFRTTableObject) {
         self.tables.push(table);
     }
 
+    /// Returns the names of all tables, learn filters, and their fields together with their ids.
+    pub(crate) fn object_ids(&self) -> Vec<(ObjectName, u32)> {
+        let mut objects = vec![];
+
+        for t in &self.tables {
+            objects.append(&mut t.object_ids());
+        }
+
+        for l in self.learn_filters.iter().flatten() {
+            objects.append(&mut l.object_ids());
+        }
+
+        objects
+    }
+
     pub fn learn_filter_get(&self, id: u32) -> Result<&LearnFilterObject, RBFRTError> {
         if self.learn_filters.is_none() {
             return Err(UnknownLearnFilter { filter_id: id });
@@ -19,6 +19,7 @@
 
 use crate::error::RBFRTError;
 use crate::error::RBFRTError::UnknownLearnFilterField;
+use crate::object::ObjectName;
 use serde::Deserialize;
 
 #[derive(Deserialize, Debug, Clone)]
@@ -35,6 +36,17 @@ pub struct LearnFilterField {
 }
 
 impl LearnFilterObject {
+    /// Returns the names of the learn filter and its fields together with their ids.
+    pub(crate) fn object_ids(&self) -> Vec<(ObjectName, u32)> {
+        let mut objects = vec![(ObjectName::learn(&self.name), self.id)];
+
+        for field in &self.fields {
+            objects.push((ObjectName::learn_field(&self.name, &field.name), field.id));
+        }
+
+        objects
+    }
+
     pub fn get_data_field_name_by_id(&self, id: u32) -> Result<String, RBFRTError> {
         for field in &self.fields {
             if field.id == id {
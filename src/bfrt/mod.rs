@@ -21,6 +21,7 @@ pub use info::BFRTInfo;
 pub(crate) use info::Convert;
 
 mod table_object;
+pub(crate) use table_object::build_entry_target;
 pub use table_object::BFRTTableObject;
 
 mod action;
@@ -23,13 +23,15 @@ use crate::bfrt_proto;
 use crate::bfrt_proto::key_field::MatchType;
 use crate::bfrt_proto::table_entry::Value;
 use crate::bfrt_proto::{
-    data_field, entity, key_field, DataField, Entity, KeyField, TableData, TableKey, Update,
+    data_field, entity, handle_id, key_field, table_attribute, table_mod_inc_flag, DataField,
+    Entity, KeyField, TableData, TableFlags, TableKey, TableModIncFlag, TableReadFlag, Update,
 };
 use crate::error::RBFRTError;
 use crate::error::RBFRTError::{
-    UnknownActionId, UnknownActionName, UnknownKeyId, UnknownKeyName, UnknownReadResult,
-    UnknownSingletonId, UnknownSingletonName,
+    UnexpectedReadRequest, UnknownActionId, UnknownActionName, UnknownKeyId, UnknownKeyName,
+    UnknownReadResult, UnknownSingletonId, UnknownSingletonName,
 };
+use crate::object::ObjectName;
 use crate::protos::bfrt_proto::TargetDevice;
 use crate::table::{MatchValue, Request, TableEntry, ToBytes};
 use prost::Message;
@@ -41,14 +43,13 @@ pub struct BFRTTableObject {
     name: String,
     id: u32,
     table_type: TableType,
-    #[allow(dead_code)]
     size: u32,
-    #[allow(dead_code)]
     has_const_default_action: Option<bool>,
     key: Vec<BFRTTableKeyObject>,
-    #[allow(dead_code)]
     action_specs: Option<Vec<BFRTAction>>,
     data: Option<Vec<BFRTData>>,
+    attributes: Option<Vec<String>>,
+    depends_on: Option<Vec<u32>>,
 }
 
 impl BFRTTableObject {
@@ -59,11 +60,157 @@ impl BFRTTableObject {
     pub fn id(&self) -> u32 {
         self.id
     }
+
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    /// Returns the names of all objects of the table together with their ids.
+    pub(crate) fn object_ids(&self) -> Vec<(ObjectName, u32)> {
+        let mut objects = vec![(ObjectName::table(&self.name), self.id)];
+
+        for k in &self.key {
+            objects.push((ObjectName::key_field(&self.name, k.name()), k.id()));
+        }
+
+        for a in self.action_specs.iter().flatten() {
+            objects.push((ObjectName::action(&self.name, &a.name), a.id));
+
+            for d in a.get_action_data() {
+                objects.push((
+                    ObjectName::data_field(&self.name, Some(&a.name), d.name()),
+                    d.id(),
+                ));
+            }
+        }
+
+        for d in self.data.iter().flatten() {
+            objects.push((
+                ObjectName::data_field(&self.name, None, d.singleton().name()),
+                d.singleton().id(),
+            ));
+        }
+
+        objects
+    }
+
+    /// Returns the ids of the tables this table depends on, e.g., the action profile of a selector.
+    pub fn depends_on(&self) -> &[u32] {
+        self.depends_on.as_deref().unwrap_or_default()
+    }
+
+    /// Returns the attributes supported by the table.
+    pub fn attributes(&self) -> &[String] {
+        self.attributes.as_deref().unwrap_or_default()
+    }
+
+    /// Returns whether the counters or meters of the table support a byte count adjustment.
+    ///
+    /// This is the case if the table declares the `MeterByteCountAdjust` or `CounterByteCountAdjust` attribute,
+    /// or has byte counter or meter spec data fields, i.e., for counter and meter tables and match-action tables with direct counters or meters.
+    pub fn supports_byte_count_adj(&self) -> bool {
+        let attribute = self
+            .attributes()
+            .iter()
+            .any(|a| a == "MeterByteCountAdjust" || a == "CounterByteCountAdjust");
+
+        let data = self.data.iter().flatten().any(|d| {
+            let name = d.singleton().name();
+            name == "$COUNTER_SPEC_BYTES" || name.starts_with("$METER_SPEC")
+        });
+
+        attribute || data
+    }
+
+    /// Returns the names of the match key fields.
+    pub fn key_names(&self) -> Vec<&str> {
+        self.key.iter().map(|k| k.name()).collect()
+    }
+
+    /// Returns the choices of the data fields with the given `name`, e.g., the algorithms of a hash table.
+    pub fn data_field_choices(&self, name: &str) -> Vec<String> {
+        let actions = self
+            .action_specs
+            .iter()
+            .flatten()
+            .flat_map(|a| a.get_action_data())
+            .filter(|d| d.name() == name)
+            .filter_map(|d| d.get_type());
+
+        let singletons = self
+            .data
+            .iter()
+            .flatten()
+            .filter(|d| d.singleton().name() == name)
+            .filter_map(|d| d.singleton().get_type().as_ref());
+
+        let mut choices = vec![];
+
+        for t in actions.chain(singletons) {
+            for c in t.choices.iter().flatten() {
+                if !choices.contains(c) {
+                    choices.push(c.to_owned());
+                }
+            }
+        }
+
+        choices
+    }
+
+    /// Builds the [DynKeyMask](bfrt_proto::DynKeyMask) of the `mask`.
+    pub fn build_dyn_key_mask(
+        &self,
+        mask: &crate::table::DynKeyMask,
+    ) -> Result<bfrt_proto::DynKeyMask, RBFRTError> {
+        let mut fields = vec![];
+
+        for (name, value) in mask.get_masks() {
+            let key = self.get_key_by_name(name)?;
+
+            fields.push(bfrt_proto::KeyFieldMask {
+                field_id: key.id(),
+                mask: value
+                    .to_vec()
+                    .convert(key.name(), key.r#type().get_width())?,
+            });
+        }
+
+        Ok(bfrt_proto::DynKeyMask { fields })
+    }
+
+    /// Parses the [DynKeyMask](bfrt_proto::DynKeyMask) of the table.
+    pub fn parse_dyn_key_mask(
+        &self,
+        mask: &bfrt_proto::DynKeyMask,
+    ) -> Result<crate::table::DynKeyMask, RBFRTError> {
+        let mut result = crate::table::DynKeyMask::new();
+
+        for field in &mask.fields {
+            let key = self.get_key_by_id(field.field_id)?;
+            result = result.mask(key.name(), field.mask.clone());
+        }
+
+        Ok(result)
+    }
+
+    /// Returns whether the default action of the table is declared `const` in the P4 program.
+    pub fn has_const_default_action(&self) -> bool {
+        self.has_const_default_action.unwrap_or(false)
+    }
 }
 
 impl BFRTTableObject {
     fn build_table_key_data(&self, request: &Request) -> Result<Option<Value>, RBFRTError> {
-        let mut val: Option<Value> = None;
+        // a handle replaces the key
+        if let Some(handle) = request.get_handle() {
+            return Ok(Some(Value::HandleId(handle)));
+        }
+
+        Ok(self.build_table_key(request)?.map(Value::Key))
+    }
+
+    fn build_table_key(&self, request: &Request) -> Result<Option<TableKey>, RBFRTError> {
+        let mut val: Option<TableKey> = None;
 
         let m = request.get_match_keys();
 
@@ -106,7 +253,7 @@ impl BFRTTableObject {
                 key_fields.push(field);
             }
 
-            val = Some(Value::Key(TableKey { fields: key_fields }));
+            val = Some(TableKey { fields: key_fields });
         }
 
         Ok(val)
@@ -146,6 +293,7 @@ impl BFRTTableObject {
                 let value = match field_type.as_str() {
                     "string" => data_field::Value::StrVal(entry.get_data().to_string()),
                     "bool" => data_field::Value::BoolVal(entry.get_data().to_bool()),
+                    "float" => data_field::Value::FloatVal(entry.get_data().to_f32()?),
                     _ => data_field::Value::Stream(
                         entry
                             .get_data()
@@ -239,21 +387,25 @@ impl BFRTTableObject {
     ) -> Result<Entity, RBFRTError> {
         let val: Option<Value> = self.build_table_key_data(request)?;
 
+        let read_flags = request.is_from_hw() || request.is_key_only();
+
         let ent = Entity {
             entity: Some(entity::Entity::TableEntry(bfrt_proto::TableEntry {
                 table_id: self.id,
                 data: None,
-                is_default_entry: false,
-                table_read_flag: None,
+                is_default_entry: request.is_default(),
+                // the deprecated flag is still evaluated by older SDEs
+                table_read_flag: read_flags.then_some(TableReadFlag {
+                    from_hw: request.is_from_hw(),
+                    key_only: request.is_key_only(),
+                }),
                 table_mod_inc_flag: None,
-                entry_tgt: if let Some(pipe) = request.get_pipe() {
-                    let mut t = *target;
-                    t.pipe_id = pipe;
-                    Some(t)
-                } else {
-                    None
-                },
-                table_flags: None,
+                entry_tgt: build_entry_target(request, target),
+                table_flags: read_flags.then_some(TableFlags {
+                    from_hw: request.is_from_hw(),
+                    key_only: request.is_key_only(),
+                    ..Default::default()
+                }),
                 value: val,
             })),
         };
@@ -261,33 +413,71 @@ impl BFRTTableObject {
         Ok(ent)
     }
 
+    /// Builds the [Entity] to read the handle of the entry with the match keys of the `request`.
+    pub fn build_handle_request(&self, request: &Request) -> Result<Entity, RBFRTError> {
+        Ok(Entity {
+            entity: Some(entity::Entity::Handle(bfrt_proto::HandleId {
+                table_id: self.id,
+                value: self.build_table_key(request)?.map(handle_id::Value::Key),
+            })),
+        })
+    }
+
+    /// Builds the [Entity] of the `attribute` of the table.
+    pub fn build_attribute_request(&self, attribute: table_attribute::Attribute) -> Entity {
+        Entity {
+            entity: Some(entity::Entity::TableAttribute(bfrt_proto::TableAttribute {
+                table_id: self.id,
+                attribute: Some(attribute),
+            })),
+        }
+    }
+
+    #[allow(deprecated)]
+    pub fn build_usage_request(&self) -> Entity {
+        Entity {
+            entity: Some(entity::Entity::TableUsage(bfrt_proto::TableUsage {
+                table_id: self.id,
+                usage: 0,
+                table_read_flag: None,
+                table_flags: None,
+            })),
+        }
+    }
+
     #[allow(deprecated)]
     pub fn build_write_request(
         &self,
         request: &Request,
         target: &TargetDevice,
     ) -> Result<Update, RBFRTError> {
+        // incremental modifications carry the direction both in the deprecated flag and the table flags
+        let mod_inc_type = match request.get_type() {
+            crate::RequestType::ModifyIncAdd => Some(table_mod_inc_flag::Type::ModIncAdd),
+            crate::RequestType::ModifyIncDelete => Some(table_mod_inc_flag::Type::ModIncDelete),
+            _ => None,
+        };
+
         let ent = Entity {
             entity: Some(entity::Entity::TableEntry(bfrt_proto::TableEntry {
                 table_id: self.id,
                 data: self.build_table_action_data(request)?,
                 is_default_entry: request.is_default(),
                 table_read_flag: None,
-                table_mod_inc_flag: None,
-                entry_tgt: if let Some(pipe) = request.get_pipe() {
-                    let mut t = *target;
-                    t.pipe_id = pipe;
-                    Some(t)
-                } else {
-                    None
-                },
-                table_flags: None,
+                table_mod_inc_flag: mod_inc_type.map(|t| TableModIncFlag { r#type: t.into() }),
+                entry_tgt: build_entry_target(request, target),
+                table_flags: mod_inc_type.map(|t| TableFlags {
+                    mod_del: t == table_mod_inc_flag::Type::ModIncDelete,
+                    ..Default::default()
+                }),
                 value: self.build_table_key_data(request)?,
             })),
         };
 
         let mode = match request.get_type() {
             crate::RequestType::Update => 2,
+            crate::RequestType::ModifyIncAdd | crate::RequestType::ModifyIncDelete => 3,
+            crate::RequestType::Upsert => 5,
             _ => 1,
         };
 
@@ -328,13 +518,7 @@ impl BFRTTableObject {
                 is_default_entry: false,
                 table_read_flag: None,
                 table_mod_inc_flag: None,
-                entry_tgt: if let Some(pipe) = request.get_pipe() {
-                    let mut t = *target;
-                    t.pipe_id = pipe;
-                    Some(t)
-                } else {
-                    None
-                },
+                entry_tgt: build_entry_target(request, target),
                 table_flags: None,
                 value: self.build_table_key_data(request)?,
             })),
@@ -348,6 +532,54 @@ impl BFRTTableObject {
         Ok(update)
     }
 
+    #[allow(deprecated)]
+    pub fn build_reset_request(
+        &self,
+        request: &Request,
+        target: &TargetDevice,
+    ) -> Result<Update, RBFRTError> {
+        let ent = Entity {
+            entity: Some(entity::Entity::TableEntry(bfrt_proto::TableEntry {
+                table_id: self.id,
+                data: None,
+                is_default_entry: request.is_default(),
+                table_read_flag: None,
+                table_mod_inc_flag: None,
+                entry_tgt: build_entry_target(request, target),
+                table_flags: None,
+                value: self.build_table_key_data(request)?,
+            })),
+        };
+
+        let update = Update {
+            r#type: 6,
+            entity: Some(ent),
+        };
+
+        Ok(update)
+    }
+
+    /// Builds the [Update] matching the [RequestType](crate::RequestType) of the `request`.
+    pub fn build_update(
+        &self,
+        request: &Request,
+        target: &TargetDevice,
+    ) -> Result<Update, RBFRTError> {
+        match request.get_type() {
+            crate::RequestType::Read => Err(UnexpectedReadRequest {
+                table_name: self.name.to_owned(),
+            }),
+            crate::RequestType::Write
+            | crate::RequestType::Update
+            | crate::RequestType::Upsert
+            | crate::RequestType::ModifyIncAdd
+            | crate::RequestType::ModifyIncDelete => self.build_write_request(request, target),
+            crate::RequestType::Operation => self.build_operation_request(request),
+            crate::RequestType::Delete => self.build_delete_request(request, target),
+            crate::RequestType::Reset => self.build_reset_request(request, target),
+        }
+    }
+
     pub(crate) fn parse_read_request(
         &self,
         entry: entity::Entity,
@@ -355,7 +587,8 @@ impl BFRTTableObject {
     ) -> Result<TableEntry, RBFRTError> {
         match entry {
             entity::Entity::TableEntry(t) => {
-                let data = t.data.as_ref().unwrap();
+                // key-only reads do not contain data
+                let data = t.data.as_ref();
 
                 Ok(TableEntry {
                     table_id: t.table_id,
@@ -391,6 +624,7 @@ impl BFRTTableObject {
                                         );
                                     }
                                 }
+                                // the handle is reported separately
                                 Value::HandleId(_) => {}
                             }
                         }
@@ -398,8 +632,13 @@ impl BFRTTableObject {
                         match_keys
                     },
                     default_entry: t.is_default_entry,
-                    action: match self.get_table_type() {
-                        TableType::MatchActionDirect => {
+                    handle: match t.value.as_ref() {
+                        Some(Value::HandleId(handle)) => Some(*handle),
+                        _ => None,
+                    },
+                    action: match (data, self.get_table_type()) {
+                        (None, _) => String::new(),
+                        (Some(data), TableType::MatchActionDirect | TableType::ActionProfile) => {
                             let action = self.get_action_by_id(data.action_id)?;
                             String::from(&action.name)
                         }
@@ -408,24 +647,29 @@ impl BFRTTableObject {
                     action_data: {
                         let mut action_data: Vec<crate::table::ActionData> = Vec::new();
 
-                        for f in &data.fields {
-                            let key_name =
-                                self.get_action_param_name(data.action_id, f.field_id)?;
-                            action_data.push(crate::table::ActionData::new(
-                                key_name,
-                                // convert values to appropriate byte representation
-                                match f.value.as_ref().unwrap() {
-                                    data_field::Value::Stream(s) => s.to_vec(),
-                                    data_field::Value::StrVal(s) => s.encode_to_vec(),
-                                    data_field::Value::BoolVal(b) => b.encode_to_vec(),
-                                    data_field::Value::FloatVal(f) => f.encode_to_vec(),
-                                    data_field::Value::IntArrVal(i) => i.val.clone().to_bytes(),
-                                    _ => unimplemented!(
-                                        "Not yet implemented. {:?}",
-                                        f.value.as_ref().unwrap()
-                                    ),
-                                },
-                            ));
+                        if let Some(data) = data {
+                            for f in &data.fields {
+                                let key_name =
+                                    self.get_action_param_name(data.action_id, f.field_id)?;
+                                action_data.push(crate::table::ActionData::new(
+                                    key_name,
+                                    // convert values to appropriate byte representation
+                                    match f.value.as_ref().unwrap() {
+                                        data_field::Value::Stream(s) => s.to_vec(),
+                                        data_field::Value::StrVal(s) => s.encode_to_vec(),
+                                        data_field::Value::BoolVal(b) => b.encode_to_vec(),
+                                        data_field::Value::FloatVal(f) => f.encode_to_vec(),
+                                        data_field::Value::IntArrVal(i) => i.val.clone().to_bytes(),
+                                        data_field::Value::BoolArrVal(b) => {
+                                            b.val.iter().map(|v| *v as u8).collect()
+                                        }
+                                        _ => unimplemented!(
+                                            "Not yet implemented. {:?}",
+                                            f.value.as_ref().unwrap()
+                                        ),
+                                    },
+                                ));
+                            }
                         }
 
                         action_data
@@ -486,7 +730,7 @@ impl BFRTTableObject {
         param_field_id: u32,
     ) -> Result<&str, RBFRTError> {
         match self.table_type {
-            TableType::MatchActionDirect => {
+            TableType::MatchActionDirect | TableType::ActionProfile => {
                 let action = self.get_action_by_id(action_id)?;
                 let action_data = action.get_action_data_by_id(param_field_id);
 
@@ -536,3 +780,29 @@ impl BFRTTableObject {
         &self.table_type
     }
 }
+
+/// Builds the target of an entry if the `request` overrides parts of the connection's `target`.
+pub(crate) fn build_entry_target(request: &Request, target: &TargetDevice) -> Option<TargetDevice> {
+    if request.get_pipe().is_none()
+        && request.get_direction().is_none()
+        && request.get_parser().is_none()
+    {
+        return None;
+    }
+
+    let mut t = *target;
+
+    if let Some(pipe) = request.get_pipe() {
+        t.pipe_id = pipe;
+    }
+
+    if let Some(direction) = request.get_direction() {
+        t.direction = direction.to_proto();
+    }
+
+    if let Some(parser) = request.get_parser() {
+        t.prsr_id = parser;
+    }
+
+    Some(t)
+}
@@ -31,6 +31,7 @@ pub enum TableMatchTypes {
 pub struct BFRTFieldType {
     pub(crate) r#type: String,
     pub(crate) width: Option<u32>,
+    pub(crate) choices: Option<Vec<String>>,
 }
 
 impl BFRTFieldType {
@@ -42,18 +43,29 @@ impl BFRTFieldType {
             "uint8" => 8,
             "bytes" => self.width.unwrap(),
             "bool" => 1,
+            "float" => 32,
             "string" => 32, // strings are handled seperately
             _ => panic!("Unknown width type: {}", self.r#type.as_str()),
         }
     }
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Debug, Clone, PartialEq)]
 pub enum TableType {
     #[serde(alias = "MatchAction_Direct")]
     MatchActionDirect,
+    #[serde(alias = "MatchAction_Indirect")]
+    MatchActionIndirect,
+    #[serde(alias = "MatchAction_Indirect_Selector")]
+    MatchActionIndirectSelector,
+    #[serde(alias = "Action")]
+    ActionProfile,
+    Selector,
     Register,
+    Counter,
     Meter,
+    Lpf,
+    Wred,
     SnapshotCfg,
     SnapshotTrigger,
     SnapshotData,
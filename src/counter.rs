@@ -0,0 +1,242 @@
+/* Copyright 2023-present University of Tuebingen, Chair of Communication Networks
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/*
+ * Steffen Lindner (steffen.lindner@uni-tuebingen.de)
+ */
+
+use crate::table::{MatchValue, TableEntry};
+use std::collections::HashMap;
+
+/// Counter index type.
+pub type IndexType = u32;
+
+/// Name of the match key of indirect counters.
+pub(crate) const COUNTER_INDEX: &str = "$COUNTER_INDEX";
+/// Name of the data field containing the byte count.
+pub(crate) const COUNTER_SPEC_BYTES: &str = "$COUNTER_SPEC_BYTES";
+/// Name of the data field containing the packet count.
+pub(crate) const COUNTER_SPEC_PKTS: &str = "$COUNTER_SPEC_PKTS";
+
+/// Represents the values of a direct or indirect counter.
+#[derive(Debug, Clone)]
+pub struct Counter {
+    /// name of the counter or the match-action table of a direct counter
+    name: String,
+    /// entries of the counter
+    entries: Vec<CounterEntry>,
+}
+
+impl Counter {
+    /// Creates a new [Counter] with the provided `name` and `entries`.
+    pub fn new(name: &str, entries: Vec<CounterEntry>) -> Counter {
+        Counter {
+            name: name.to_owned(),
+            entries,
+        }
+    }
+
+    /// Returns the `name` of the counter.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns all `entries` of the counter.
+    pub fn entries(&self) -> &Vec<CounterEntry> {
+        &self.entries
+    }
+
+    /// Returns the [CounterEntry] at a specific `index` of an indirect counter.
+    ///
+    /// If the counter was read from multiple pipes, the entry of the first pipe is returned.
+    pub fn get(&self, index: IndexType) -> Option<&CounterEntry> {
+        self.entries.iter().find(|e| e.index == Some(index))
+    }
+
+    /// Parses [TableEntries](TableEntry) read from the `pipe` into [CounterEntries](CounterEntry).
+    pub(crate) fn parse_counter_entries(
+        entries: Vec<TableEntry>,
+        pipe: Option<u32>,
+    ) -> Vec<CounterEntry> {
+        entries
+            .into_iter()
+            .map(|e| {
+                let mut match_keys = e.match_keys;
+
+                let index = match_keys
+                    .remove(COUNTER_INDEX)
+                    .map(|i| crate::table::ToBytes::to_u32(i.get_exact_value()));
+
+                CounterEntry {
+                    index,
+                    match_keys,
+                    pipe,
+                    bytes: e
+                        .action_data
+                        .iter()
+                        .find(|d| d.get_key() == COUNTER_SPEC_BYTES)
+                        .map(|d| d.as_u64()),
+                    packets: e
+                        .action_data
+                        .iter()
+                        .find(|d| d.get_key() == COUNTER_SPEC_PKTS)
+                        .map(|d| d.as_u64()),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Represents the values of a counter at a specific index or of a direct counter of a table entry.
+#[derive(Debug, Clone)]
+pub struct CounterEntry {
+    index: Option<IndexType>,
+    match_keys: HashMap<String, MatchValue>,
+    pipe: Option<u32>,
+    bytes: Option<u64>,
+    packets: Option<u64>,
+}
+
+impl CounterEntry {
+    /// Returns the `index` of an indirect counter entry.
+    pub fn get_index(&self) -> Option<IndexType> {
+        self.index
+    }
+
+    /// Returns the match keys of the table entry of a direct counter.
+    pub fn get_match_keys(&self) -> &HashMap<String, MatchValue> {
+        &self.match_keys
+    }
+
+    /// Returns the pipe the counter was read from, `None` if it was read from all pipes.
+    pub fn get_pipe(&self) -> Option<u32> {
+        self.pipe
+    }
+
+    /// Returns the number of bytes, `None` if the counter does not count bytes.
+    pub fn bytes(&self) -> Option<u64> {
+        self.bytes
+    }
+
+    /// Returns the number of packets, `None` if the counter does not count packets.
+    pub fn packets(&self) -> Option<u64> {
+        self.packets
+    }
+}
+
+/// Represents a counter request.
+///
+/// Indirect counters are addressed by their [index](Request::index), direct counters by the [match keys](Request::match_key) of the table entry.
+///
+/// # Example
+///
+/// ```
+/// use rbfrt::counter::Request;
+///
+/// let req = Request::new("ingress.port_counter")
+///     .index(5)
+///     .sync(true);
+///
+/// assert_eq!(req.get_index(), &Some(5));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Request {
+    name: String,
+    index: Option<IndexType>,
+    match_keys: HashMap<String, MatchValue>,
+    pipe: Option<u32>,
+    sync: bool,
+    from_hw: bool,
+}
+
+impl Request {
+    /// Creates a new [Request] for the counter with the given `name`.
+    ///
+    /// For direct counters, `name` is the name of the match-action table.
+    pub fn new(name: &str) -> Request {
+        Request {
+            name: name.to_owned(),
+            index: None,
+            match_keys: HashMap::new(),
+            pipe: None,
+            sync: false,
+            from_hw: false,
+        }
+    }
+
+    /// Returns a new [Request] with the `index` of an indirect counter.
+    pub fn index(self, index: IndexType) -> Request {
+        Request {
+            index: Some(index),
+            ..self
+        }
+    }
+
+    /// Adds a match key of the table entry of a direct counter.
+    pub fn match_key(mut self, name: &str, match_value: MatchValue) -> Request {
+        self.match_keys.insert(name.to_owned(), match_value);
+        self
+    }
+
+    /// Sets the pipe to read the counter from.
+    ///
+    /// Only tables with an asymmetric pipe scope can be read per pipe, see [set_entry_scope](crate::SwitchConnection::set_entry_scope).
+    pub fn pipe(self, pipe: u32) -> Request {
+        Request {
+            pipe: Some(pipe),
+            ..self
+        }
+    }
+
+    /// Sets if the counters are synchronized from the hardware before they are read.
+    pub fn sync(self, sync: bool) -> Request {
+        Request { sync, ..self }
+    }
+
+    /// Sets if the counter is read from the hardware instead of the software shadow of the switch.
+    pub fn from_hw(self, from_hw: bool) -> Request {
+        Request { from_hw, ..self }
+    }
+
+    /// Returns the counter's `name` of the [Request].
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the counter's `index` in the [Request].
+    pub fn get_index(&self) -> &Option<IndexType> {
+        &self.index
+    }
+
+    /// Returns the match keys of the [Request].
+    pub fn get_match_keys(&self) -> &HashMap<String, MatchValue> {
+        &self.match_keys
+    }
+
+    /// Returns the pipe of the [Request], if set.
+    pub fn get_pipe(&self) -> Option<u32> {
+        self.pipe
+    }
+
+    /// Returns if the counters are synchronized before they are read.
+    pub fn is_sync(&self) -> bool {
+        self.sync
+    }
+
+    /// Returns if the counter is read from the hardware.
+    pub fn is_from_hw(&self) -> bool {
+        self.from_hw
+    }
+}
@@ -19,9 +19,17 @@
 
 use std::error::Error;
 
-use crate::error::RBFRTError::GRPCError;
+use crate::error::RBFRTError::{
+    AtomicityNotSupported, BatchError, EntryAlreadyExists, EntryNotFound, GRPCError,
+    InvalidArgument, NotMaster, PermissionDenied, TableFull, Unavailable, Unimplemented,
+};
+use crate::protos::bfrt_proto;
+use crate::protos::google::rpc;
+use crate::table::{Atomicity, Direction, MatchValue, Request};
+use prost::Message;
+use std::collections::HashMap;
 use thiserror::Error;
-use tonic::Status;
+use tonic::{Code, Status};
 
 #[derive(Error, Debug)]
 pub enum RBFRTError {
@@ -84,6 +92,94 @@ pub enum RBFRTError {
         target: String,
         orig_e: Box<dyn Error>,
     },
+    #[error("Atomicity {atomicity:?} is not supported by the switch. Original: {message}.")]
+    AtomicityNotSupported {
+        atomicity: Atomicity,
+        message: String,
+    },
+    #[error(
+        "Read request for table {table_name} cannot be dispatched together with write requests."
+    )]
+    UnexpectedReadRequest { table_name: String },
+    #[error("{} update(s) of the batch failed. Original: {message}.", .failures.len())]
+    BatchError {
+        message: String,
+        failures: Vec<UpdateError>,
+    },
+    #[error("Write request of {size} bytes exceeds the maximum message size of {limit} bytes.")]
+    TransactionTooLarge { size: usize, limit: usize },
+    #[error("Entry already exists. Original: {message}.")]
+    EntryAlreadyExists { message: String },
+    #[error("Entry not found. Original: {message}.")]
+    EntryNotFound { message: String },
+    #[error("Table is full. Original: {message}.")]
+    TableFull { message: String },
+    #[error("Invalid argument. Original: {message}.")]
+    InvalidArgument { message: String },
+    #[error("Client is not the master of the device. Original: {message}.")]
+    NotMaster { message: String },
+    #[error("Switch is unavailable. Original: {message}.")]
+    Unavailable { message: String },
+    #[error("Permission denied. Original: {message}.")]
+    PermissionDenied { message: String },
+    #[error("Operation is not supported by the switch. Original: {message}.")]
+    Unimplemented { message: String },
+    #[error("Default action of table {table_name} is const and cannot be changed.")]
+    ConstDefaultAction { table_name: String },
+    #[error("Default entry of table {table_name} cannot have match keys.")]
+    DefaultEntryMatchKeys { table_name: String },
+    #[error("Action {action_name} cannot be used as default action of table {table_name}.")]
+    InvalidDefaultAction {
+        action_name: String,
+        table_name: String,
+    },
+    #[error(
+        "Object {object} has id {local_id} in the cached schema but id {server_id} on the switch."
+    )]
+    SchemaMismatch {
+        object: String,
+        local_id: u32,
+        server_id: u32,
+    },
+    #[error("Table {table_name} is symmetric and cannot be addressed to pipe {pipe}. Set an asymmetric entry scope first.")]
+    SymmetricTable { table_name: String, pipe: u32 },
+    #[error("Table {table_name} is gress-symmetric and cannot be addressed to direction {direction:?}. Set an asymmetric entry scope first.")]
+    GressSymmetricTable {
+        table_name: String,
+        direction: Direction,
+    },
+    #[error("Hash algorithm {algorithm} does not exist for table {table_name}.")]
+    UnknownHashAlgorithm {
+        table_name: String,
+        algorithm: String,
+    },
+    #[error("Table {table_name} does not support the attribute {attribute}.")]
+    AttributeNotSupported {
+        table_name: String,
+        attribute: String,
+    },
+    #[error("Table {table_name} is not an action selector.")]
+    NoSelector { table_name: String },
+    #[error("Multicast node {node_id} of group {mgid} does not exist.")]
+    UnknownMulticastNode { node_id: u32, mgid: u16 },
+    #[error("Multicast {kind} {id} is already in use with a different configuration.")]
+    MulticastIdInUse { kind: String, id: u32 },
+    #[error("{name} is still referenced by table {referenced_by}.")]
+    ObjectInUse { name: String, referenced_by: String },
+    #[error("Member {member_id} is not part of group {group_id} of selector {table_name}.")]
+    UnknownGroupMember {
+        table_name: String,
+        group_id: u32,
+        member_id: u32,
+    },
+    #[error("Requests of a single call refer to different objects {first} and {other}.")]
+    MixedRequestNames { first: String, other: String },
+    #[error("Invalid meter spec: {message}.")]
+    InvalidMeterSpec { message: String },
+    #[error("Invalid LPF spec: {message}.")]
+    InvalidLpfSpec { message: String },
+    #[error("Invalid WRED spec: {message}.")]
+    InvalidWredSpec { message: String },
     #[error("Switch request is empty.")]
     RequestEmpty {},
     #[error("Generic error occurred. Message: {message}.")]
@@ -92,12 +188,213 @@ pub enum RBFRTError {
 
 impl From<Status> for RBFRTError {
     fn from(value: Status) -> Self {
-        GRPCError {
-            message: value.message().to_owned(),
-            details: format!("{value:#?}"),
+        match classify(value.code() as i32, 0, value.message()) {
+            Some(e) => e,
+            None => GRPCError {
+                message: value.message().to_owned(),
+                details: format!("{value:#?}"),
+            },
         }
     }
 }
 
+/// Status codes of the BF SDE (`bf_status_t`) that are reported as target-specific error codes.
+const BF_NOT_READY: i32 = 1;
+const BF_INVALID_ARG: i32 = 3;
+const BF_ALREADY_EXISTS: i32 = 4;
+const BF_OBJECT_NOT_FOUND: i32 = 6;
+const BF_NO_SPACE: i32 = 9;
+const BF_EAGAIN: i32 = 10;
+const BF_TXN_NOT_SUPPORTED: i32 = 12;
+const BF_TABLE_LOCKED: i32 = 13;
+const BF_DEVICE_LOCKED: i32 = 21;
+
+/// Maps a gRPC canonical code and a BF status code to a typed [RBFRTError].
+///
+/// The BF status `code` takes precedence as it is more specific. Use `0` if it is unknown.
+/// Returns `None` if the error cannot be classified.
+fn classify(canonical_code: i32, code: i32, message: &str) -> Option<RBFRTError> {
+    let message = message.to_owned();
+
+    match code {
+        BF_ALREADY_EXISTS => return Some(EntryAlreadyExists { message }),
+        BF_OBJECT_NOT_FOUND => return Some(EntryNotFound { message }),
+        BF_NO_SPACE => return Some(TableFull { message }),
+        BF_INVALID_ARG => return Some(InvalidArgument { message }),
+        BF_NOT_READY | BF_EAGAIN | BF_TABLE_LOCKED | BF_DEVICE_LOCKED => {
+            return Some(Unavailable { message })
+        }
+        _ => {}
+    }
+
+    match Code::from_i32(canonical_code) {
+        Code::AlreadyExists => Some(EntryAlreadyExists { message }),
+        Code::NotFound => Some(EntryNotFound { message }),
+        // an exceeded gRPC message size is reported with the same code
+        Code::ResourceExhausted if !message.to_lowercase().contains("message") => {
+            Some(TableFull { message })
+        }
+        Code::InvalidArgument => Some(InvalidArgument { message }),
+        Code::PermissionDenied if message.to_lowercase().contains("master") => {
+            Some(NotMaster { message })
+        }
+        Code::PermissionDenied => Some(PermissionDenied { message }),
+        Code::Unavailable => Some(Unavailable { message }),
+        Code::Unimplemented => Some(Unimplemented { message }),
+        _ => None,
+    }
+}
+
+/// Represents a single failed update of a batch write.
+#[derive(Debug, Clone)]
+pub struct UpdateError {
+    /// Index of the failed request in the dispatched batch.
+    pub index: usize,
+    /// Name of the table of the failed request.
+    pub table_name: String,
+    /// Match keys of the failed request.
+    pub match_keys: HashMap<String, MatchValue>,
+    /// gRPC canonical error code.
+    pub canonical_code: i32,
+    /// Target-specific error code.
+    pub code: i32,
+    /// Error message reported by the switch.
+    pub message: String,
+}
+
+impl UpdateError {
+    /// Returns the typed [RBFRTError] of the failed update.
+    ///
+    /// Falls back to [GRPCError] if the error cannot be classified.
+    pub fn to_error(&self) -> RBFRTError {
+        classify(self.canonical_code, self.code, &self.message).unwrap_or_else(|| GRPCError {
+            message: self.message.clone(),
+            details: format!("{self:?}"),
+        })
+    }
+
+    /// Returns if retrying the failed update may succeed.
+    ///
+    /// See [RBFRTError::is_retryable].
+    pub fn is_retryable(&self) -> bool {
+        self.to_error().is_retryable()
+    }
+}
+
+impl RBFRTError {
+    /// Returns if retrying the failed request may succeed without changing it.
+    ///
+    /// This is the case if the switch is temporarily [Unavailable], or if all failed updates of a [BatchError] are retryable.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Unavailable { .. } => true,
+            BatchError { failures, .. } => failures.iter().all(|f| f.is_retryable()),
+            _ => false,
+        }
+    }
+
+    /// Returns the failed updates of a write request of the `requests`.
+    ///
+    /// The `offset` is added to the index of each failed update.
+    /// If the [Status] contains no per-update errors, all `requests` are reported as failed.
+    pub(crate) fn update_failures(
+        value: &Status,
+        requests: &[Request],
+        offset: usize,
+    ) -> Vec<UpdateError> {
+        let errors = decode_update_errors(value);
+
+        let errors: Vec<(usize, i32, i32, String)> = if errors.is_empty() {
+            (0..requests.len())
+                .map(|index| (index, value.code() as i32, 0, value.message().to_owned()))
+                .collect()
+        } else {
+            errors
+                .into_iter()
+                .filter(|(_, e)| e.canonical_code != 0)
+                .map(|(index, e)| (index, e.canonical_code, e.code, e.message))
+                .collect()
+        };
+
+        errors
+            .into_iter()
+            .map(|(index, canonical_code, code, message)| UpdateError {
+                index: index + offset,
+                table_name: requests
+                    .get(index)
+                    .map(|r| r.get_table_name().to_owned())
+                    .unwrap_or_default(),
+                match_keys: requests
+                    .get(index)
+                    .map(|r| r.get_match_keys().clone())
+                    .unwrap_or_default(),
+                canonical_code,
+                code,
+                message,
+            })
+            .collect()
+    }
+
+    /// Converts the [Status] of a write request of the `requests` with the given [Atomicity].
+    ///
+    /// A rejected transaction is reported as [AtomicityNotSupported].
+    /// Per-update errors contained in the status details are reported as [BatchError].
+    pub(crate) fn from_write_status(
+        value: Status,
+        atomicity: Atomicity,
+        requests: &[Request],
+    ) -> Self {
+        let rejected = value.code() == tonic::Code::Unimplemented
+            || decode_update_errors(&value).iter().any(|(_, e)| {
+                e.code == BF_TXN_NOT_SUPPORTED || e.canonical_code == Code::Unimplemented as i32
+            });
+
+        if atomicity != Atomicity::ContinueOnError && rejected {
+            return AtomicityNotSupported {
+                atomicity,
+                message: value.message().to_owned(),
+            };
+        }
+
+        // errors that do not refer to single updates are reported as a whole
+        if decode_update_errors(&value).is_empty() {
+            return value.into();
+        }
+
+        let failures = Self::update_failures(&value, requests, 0);
+
+        if failures.is_empty() {
+            value.into()
+        } else if requests.len() == 1 && failures.len() == 1 {
+            // a single request is reported with its typed error
+            failures.first().unwrap().to_error()
+        } else {
+            BatchError {
+                message: value.message().to_owned(),
+                failures,
+            }
+        }
+    }
+}
+
+/// Decodes the [bfrt_proto::Error] of each update that is packed into the details of the [Status].
+///
+/// The n-th error belongs to the n-th update of the write request.
+fn decode_update_errors(value: &Status) -> Vec<(usize, bfrt_proto::Error)> {
+    match rpc::Status::decode(value.details()) {
+        Ok(status) => status
+            .details
+            .iter()
+            .enumerate()
+            .filter_map(|(index, any)| {
+                bfrt_proto::Error::decode(any.value.as_slice())
+                    .ok()
+                    .map(|e| (index, e))
+            })
+            .collect(),
+        Err(_) => vec![],
+    }
+}
+
 unsafe impl Send for RBFRTError {}
 unsafe impl Sync for RBFRTError {}
@@ -59,49 +59,102 @@
 
 mod bfrt;
 mod core;
+pub mod counter;
 pub mod error;
+pub mod lpf;
+pub mod meter;
+pub mod object;
 mod protos;
 pub mod register;
+pub mod selector;
 pub mod table;
 pub mod util;
+pub mod wred;
 
 use crate::bfrt_proto::forwarding_pipeline_config::Profile;
+use crate::bfrt_proto::idle_timeout_notification::NotificationType;
 use crate::bfrt_proto::set_forwarding_pipeline_config_request::{Action, DevInitMode};
+use crate::bfrt_proto::table_attribute::Attribute;
 use crate::bfrt_proto::{
     ForwardingPipelineConfig, ReadResponse, SetForwardingPipelineConfigRequest,
     StreamMessageRequest, StreamMessageResponse, WriteResponse,
 };
+use crate::counter::{Counter, COUNTER_INDEX, COUNTER_SPEC_BYTES, COUNTER_SPEC_PKTS};
 use crate::error::RBFRTError;
 use crate::error::RBFRTError::{
-    ConnectionError, GRPCError, GetForwardingPipelineError, P4ProgramError, RequestEmpty,
-    UnknownReadResult,
+    AttributeNotSupported, ConnectionError, ConstDefaultAction, DefaultEntryMatchKeys,
+    EntryNotFound, GetForwardingPipelineError, GressSymmetricTable, InvalidDefaultAction,
+    InvalidLpfSpec, InvalidMeterSpec, InvalidWredSpec, MixedRequestNames, NoSelector, ObjectInUse,
+    P4ProgramError, RequestEmpty, SchemaMismatch, SymmetricTable, TransactionTooLarge,
+    UnknownGroupMember, UnknownHashAlgorithm, UnknownReadResult, UnknownTable,
 };
+use crate::lpf::{
+    Lpf, LPF_INDEX, LPF_SPEC_DECAY_TIME_CONSTANT_NS, LPF_SPEC_GAIN_TIME_CONSTANT_NS,
+    LPF_SPEC_OUT_SCALE_DOWN_FACTOR, LPF_SPEC_TYPE,
+};
+use crate::meter::{Meter, METER_INDEX};
 use crate::protos::bfrt_proto::data_field::Value;
 use crate::protos::bfrt_proto::entity::Entity;
 use crate::protos::bfrt_proto::stream_message_response::Update;
 use crate::protos::bfrt_proto::{ReadRequest, WriteRequest};
 use crate::register::Register;
+use crate::selector::{
+    ActionMember, SelectorGroup, ACTION_MEMBER_ID, ACTION_MEMBER_STATUS, ACTION_MEMBER_WEIGHT,
+    MAX_GROUP_SIZE, SELECTOR_GROUP_ID,
+};
 use crate::table::MatchValue;
-use crate::util::Digest;
-use bfrt::BFRTInfo;
+use crate::util::{Digest, IdleTimeoutEvent};
+use crate::wred::{
+    Wred, WRED_INDEX, WRED_SPEC_MAX_PROBABILITY, WRED_SPEC_MAX_THRESH_CELLS,
+    WRED_SPEC_MIN_THRESH_CELLS, WRED_SPEC_TIME_CONSTANT_NS,
+};
+use async_stream::try_stream;
+use bfrt::{build_entry_target, BFRTFieldType, BFRTInfo, BFRTTableObject, TableType};
 use bfrt_proto::bf_runtime_client::BfRuntimeClient;
 use bfrt_proto::GetForwardingPipelineConfigRequest;
 use bfrt_proto::TargetDevice;
+use futures_core::Stream;
 use log::{debug, info, warn};
+use object::ObjectName;
+use prost::Message;
 use protos::bfrt_proto;
 use std::collections::HashMap;
 use std::io::Read;
+use std::pin::Pin;
+use std::sync::RwLock;
 use std::{fs, str};
-use table::{Request, RequestType, TableEntry};
+use table::{
+    Atomicity, Direction, DynHashing, DynKeyMask, EntryScope, IdleTable, PreDeviceConfig, Request,
+    RequestType, TableEntry, TableOperation, TableUsage, Transaction,
+};
 use tokio::sync::Mutex;
+use tokio::task::JoinSet;
 use tokio_stream::wrappers::ReceiverStream;
 use tonic::transport::Channel;
-use tonic::{Response, Streaming};
+use tonic::{Response, Status, Streaming};
 
 /// Size of the internal digest queue
 /// Up to 20k elements with back pressure
 const DIGEST_QUEUE_SIZE: usize = 20000;
 
+/// Pipe id that addresses all pipes of the device.
+const ALL_PIPES: u32 = 0xffff;
+
+/// Name of the table attribute for dynamic key masks in the schema.
+const DYN_KEY_MASK_ATTRIBUTE: &str = "DynamicKeyMask";
+/// Schema attribute of tables that support dynamic hashing.
+const DYN_HASHING_ATTRIBUTE: &str = "DynamicHashing";
+
+/// Maximum size of a gRPC message sent to or received from the switch.
+const MAX_MESSAGE_SIZE: usize = 16 * 1024 * 1024;
+
+/// Default maximum encoded size of a single write request.
+/// Larger batches are split into multiple write requests.
+const DEFAULT_BATCH_MESSAGE_SIZE: usize = 4 * 1024 * 1024;
+
+/// Default number of write requests of a split batch that are in flight concurrently.
+const DEFAULT_BATCH_CONCURRENCY: usize = 1;
+
 #[allow(dead_code)]
 #[allow(clippy::large_enum_variant)]
 enum DispatchResult {
@@ -109,10 +162,17 @@ enum DispatchResult {
         response: Response<Streaming<ReadResponse>>,
     },
     WriteResult {
-        response: Response<WriteResponse>,
+        responses: Vec<WriteResponse>,
     },
 }
 
+/// [Stream](futures_core::Stream) of [TableEntries](TableEntry) read from the switch.
+pub type TableEntryStream<'a> =
+    Pin<Box<dyn Stream<Item = Result<TableEntry, RBFRTError>> + Send + 'a>>;
+
+/// Result of a single chunk of a split batch: offset of the chunk, number of updates, and response.
+type ChunkResult = (usize, usize, Result<Response<WriteResponse>, Status>);
+
 /// A builder to create the [SwitchConnection] between the switch and the controller.
 ///
 /// # Example
@@ -139,6 +199,10 @@ pub struct SwitchConnectionBuilder {
     client_id: u32,
     p4_name: Option<String>,
     config: Option<String>,
+    batch_message_size: usize,
+    batch_concurrency: usize,
+    verify_schema: bool,
+    target: TargetDevice,
 }
 
 impl SwitchConnectionBuilder {
@@ -176,6 +240,59 @@ impl SwitchConnectionBuilder {
         self
     }
 
+    /// Sets the maximum encoded size in bytes of a single write request.
+    ///
+    /// Batches that exceed this size are split into multiple write requests, [Transactions](crate::table::Transaction) are never split.
+    /// Defaults to 4 MiB, the default receive limit of gRPC servers.
+    pub fn batch_message_size(mut self, size: usize) -> SwitchConnectionBuilder {
+        self.batch_message_size = size.min(MAX_MESSAGE_SIZE);
+        self
+    }
+
+    /// Sets the number of write requests of a split batch that are sent concurrently.
+    ///
+    /// Defaults to 1, i.e., the chunks of a split batch are sent one after another in the order of the batch.
+    /// With a higher value, the chunks of a batch whose requests share the same type are sent concurrently
+    /// and may be applied by the switch in any order. Only opt in if the requests of a batch do not depend on each other.
+    pub fn batch_concurrency(mut self, concurrency: usize) -> SwitchConnectionBuilder {
+        self.batch_concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Sets the pipe all requests are addressed to by default.
+    ///
+    /// Defaults to all pipes. Can be overridden per request with [Request::pipe](crate::table::Request::pipe).
+    pub fn default_pipe(mut self, pipe: u32) -> SwitchConnectionBuilder {
+        self.target.pipe_id = pipe;
+        self
+    }
+
+    /// Sets the [Direction] all requests are addressed to by default.
+    ///
+    /// Defaults to [All](Direction::All). Can be overridden per request with [Request::direction](crate::table::Request::direction).
+    pub fn default_direction(mut self, direction: Direction) -> SwitchConnectionBuilder {
+        self.target.direction = direction.to_proto();
+        self
+    }
+
+    /// Sets the parser all requests are addressed to by default.
+    ///
+    /// Defaults to all parsers. Can be overridden per request with [Request::parser](crate::table::Request::parser).
+    pub fn default_parser(mut self, parser: u32) -> SwitchConnectionBuilder {
+        self.target.prsr_id = parser;
+        self
+    }
+
+    /// Sets whether the ids of the cached schema are verified against the switch when connecting.
+    ///
+    /// If enabled, [connect](crate::SwitchConnectionBuilder::connect) fails with [SchemaMismatch](RBFRTError::SchemaMismatch) if an id differs.
+    /// The verification is skipped with a warning if the switch does not support resolving object ids.
+    /// Defaults to `false`.
+    pub fn verify_schema(mut self, verify: bool) -> SwitchConnectionBuilder {
+        self.verify_schema = verify;
+        self
+    }
+
     /// Creates the [SwitchConnection] between the switch and controller.
     pub async fn connect(self) -> Result<SwitchConnection, RBFRTError> {
         debug!(
@@ -187,8 +304,8 @@ impl SwitchConnectionBuilder {
             Ok(client) => {
                 let bf_client = Mutex::new(
                     client
-                        .max_decoding_message_size(16 * 1024 * 1024)
-                        .max_encoding_message_size(16 * 1024 * 1024),
+                        .max_decoding_message_size(MAX_MESSAGE_SIZE)
+                        .max_encoding_message_size(MAX_MESSAGE_SIZE),
                 );
 
                 let (request_tx, request_rx) =
@@ -197,6 +314,8 @@ impl SwitchConnectionBuilder {
                     tokio::sync::mpsc::channel::<StreamMessageResponse>(DIGEST_QUEUE_SIZE);
                 let (digest_sender, digest_receiver) =
                     crossbeam_channel::bounded(DIGEST_QUEUE_SIZE);
+                let (idle_timeout_sender, idle_timeout_receiver) =
+                    crossbeam_channel::bounded(DIGEST_QUEUE_SIZE);
                 let mut connection = SwitchConnection {
                     ip: self.ip,
                     port: self.port,
@@ -204,16 +323,19 @@ impl SwitchConnectionBuilder {
                     client_id: self.client_id,
                     bf_client,
                     config: self.config,
+                    batch_message_size: self.batch_message_size,
+                    batch_concurrency: self.batch_concurrency,
+                    verify_schema: self.verify_schema,
+                    entry_scopes: RwLock::new(HashMap::new()),
                     bfrt_info: None,
                     target: TargetDevice {
                         device_id: self.device_id,
-                        pipe_id: 0xffff,
-                        direction: 0xff,
-                        prsr_id: 0xff,
+                        ..self.target
                     },
                     p4_name: self.p4_name,
                     send_channel: request_tx,
                     digest_queue: digest_receiver,
+                    idle_timeout_queue: idle_timeout_receiver,
                 };
 
                 if connection.config.is_some() {
@@ -232,7 +354,11 @@ impl SwitchConnectionBuilder {
                 connection.bind_forwarding_pipeline().await?;
                 connection.bfrt_info = Some(connection.load_pipeline().await?);
 
-                connection.start_notification_thread(response_rx, digest_sender);
+                connection.start_notification_thread(
+                    response_rx,
+                    digest_sender,
+                    idle_timeout_sender,
+                );
 
                 info!(
                     "Switch connection to {}:{} successful.",
@@ -281,7 +407,14 @@ pub struct SwitchConnection {
     send_channel: tokio::sync::mpsc::Sender<StreamMessageRequest>,
     /// Queue containing all digests of the connected switch.
     pub digest_queue: crossbeam_channel::Receiver<Digest>,
+    /// Queue containing all idle timeout notifications of the connected switch.
+    pub idle_timeout_queue: crossbeam_channel::Receiver<IdleTimeoutEvent>,
     config: Option<String>,
+    batch_message_size: usize,
+    batch_concurrency: usize,
+    verify_schema: bool,
+    /// Entry scopes of the tables that were read or written by this connection.
+    entry_scopes: RwLock<HashMap<u32, EntryScope>>,
 }
 
 impl SwitchConnection {
@@ -296,6 +429,15 @@ impl SwitchConnection {
             client_id: 1,
             p4_name: None,
             config: None,
+            batch_message_size: DEFAULT_BATCH_MESSAGE_SIZE,
+            batch_concurrency: DEFAULT_BATCH_CONCURRENCY,
+            verify_schema: false,
+            target: TargetDevice {
+                device_id: 0,
+                pipe_id: ALL_PIPES,
+                direction: Direction::All.to_proto(),
+                prsr_id: 0xff,
+            },
         }
     }
 
@@ -346,7 +488,9 @@ impl SwitchConnection {
             loop {
                 match resp.message().await {
                     Ok(Some(msg)) => match msg.clone().update.unwrap() {
-                        Update::Subscribe(_) | Update::Digest(_) => {
+                        Update::Subscribe(_)
+                        | Update::Digest(_)
+                        | Update::IdleTimeoutNotification(_) => {
                             if let Err(e) = response_tx.try_send(msg) {
                                 warn!("Failed to send notification: {e}");
                             }
@@ -396,7 +540,8 @@ impl SwitchConnection {
     /// Loads the pipeline information from the switch
     async fn load_pipeline(&self) -> Result<BFRTInfo, RBFRTError> {
         debug!("Loading pipeline.");
-        match self
+        // release the client before the schema is verified
+        let pipeline = self
             .bf_client
             .lock()
             .await
@@ -404,8 +549,9 @@ impl SwitchConnection {
                 device_id: self.device_id,
                 client_id: self.client_id,
             })
-            .await
-        {
+            .await;
+
+        match pipeline {
             Ok(pipeline) => {
                 let msg = pipeline.into_inner();
 
@@ -418,6 +564,12 @@ impl SwitchConnection {
                 for v in msg.config {
                     if v.p4_name == self.p4_name.clone().unwrap() {
                         let mut tmp: BFRTInfo = serde_json::from_slice(&v.bfruntime_info).unwrap();
+
+                        // only the objects of the P4 program can be resolved by its name
+                        if self.verify_schema {
+                            self.verify_schema(&tmp).await?;
+                        }
+
                         for t in &non_p4_tables {
                             tmp.add_table(t.clone());
                         }
@@ -438,10 +590,43 @@ impl SwitchConnection {
         }
     }
 
+    /// Verifies that the ids of the cached `bfrt_info` match the ids reported by the switch.
+    async fn verify_schema(&self, bfrt_info: &BFRTInfo) -> Result<(), RBFRTError> {
+        debug!("Verifying schema.");
+        let (names, local_ids): (Vec<ObjectName>, Vec<u32>) =
+            bfrt_info.object_ids().into_iter().unzip();
+
+        if names.is_empty() {
+            return Ok(());
+        }
+
+        let server_ids = match self.resolve_object_ids(names.clone()).await {
+            Ok(ids) => ids,
+            Err(RBFRTError::Unimplemented { message }) => {
+                warn!("Switch does not support resolving object ids, schema is not verified: {message}");
+                return Ok(());
+            }
+            Err(e) => return Err(e),
+        };
+
+        for ((name, local_id), server_id) in names.iter().zip(local_ids).zip(server_ids) {
+            if local_id != server_id {
+                return Err(SchemaMismatch {
+                    object: name.to_string(),
+                    local_id,
+                    server_id,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
     fn start_notification_thread(
         &self,
         mut response_rx: tokio::sync::mpsc::Receiver<StreamMessageResponse>,
         digest_queue: crossbeam_channel::Sender<Digest>,
+        idle_timeout_queue: crossbeam_channel::Sender<IdleTimeoutEvent>,
     ) {
         let local_bfrt_info = self.bfrt_info.clone();
 
@@ -493,8 +678,44 @@ impl SwitchConnection {
                             }
                         }
                     }
+                    Update::IdleTimeoutNotification(notification) => {
+                        let notification_type = notification.r#type();
+
+                        let Some(entry) = notification.table_entry else {
+                            warn!("Received idle timeout notification without entry.");
+                            continue;
+                        };
+
+                        let event = bfrt_info.table_get_by_id(entry.table_id).and_then(|table| {
+                            table.parse_read_request(Entity::TableEntry(entry), table.name())
+                        });
+
+                        match event {
+                            Ok(entry) => {
+                                let event = match notification_type {
+                                    NotificationType::EntryIdle => IdleTimeoutEvent::EntryIdle {
+                                        table_name: entry.table_name,
+                                        match_keys: entry.match_keys,
+                                    },
+                                    NotificationType::EntryActive => {
+                                        IdleTimeoutEvent::EntryActive {
+                                            table_name: entry.table_name,
+                                            match_keys: entry.match_keys,
+                                        }
+                                    }
+                                };
+
+                                if let Err(e) = idle_timeout_queue.try_send(event) {
+                                    warn!("Failed to queue idle timeout event: {e}");
+                                }
+                            }
+                            Err(err) => {
+                                warn!("Received an error while decoding idle timeout notification: {err}");
+                            }
+                        }
+                    }
                     _ => {
-                        warn!("Received not supported notification. Only Digests are currently supported.")
+                        warn!("Received not supported notification. Only Digests and idle timeouts are currently supported.")
                     }
                 }
             }
@@ -575,10 +796,7 @@ impl SwitchConnection {
 
         match req {
             Ok(_) => Ok(()),
-            Err(e) => Err(GRPCError {
-                message: e.to_string(),
-                details: format!("{:?}", e.details()),
-            }),
+            Err(e) => Err(RBFRTError::from(e)),
         }
     }
 
@@ -618,10 +836,7 @@ impl SwitchConnection {
             }
             Err(e) => {
                 warn!("Bind forwarding pipeline failed.");
-                Err(GRPCError {
-                    message: e.to_string(),
-                    details: format!("{:?}", e.details()),
-                })
+                Err(RBFRTError::from(e))
             }
         }
     }
@@ -635,6 +850,26 @@ impl SwitchConnection {
         }
     }
 
+    /// Commits all requests of a [Transaction] in a single write request.
+    ///
+    /// The requests are applied in the order they were added to the [Transaction] with the [Atomicity] of the [Transaction].
+    /// Unlike batches, a [Transaction] is never split, see [batch_message_size](crate::SwitchConnectionBuilder::batch_message_size).
+    /// Returns [TransactionTooLarge](RBFRTError::TransactionTooLarge) if the write request exceeds the maximum gRPC message size,
+    /// and [AtomicityNotSupported](RBFRTError::AtomicityNotSupported) if the switch rejects the requested [Atomicity].
+    pub async fn commit_transaction(&self, transaction: Transaction) -> Result<(), RBFRTError> {
+        debug!("Commit transaction {transaction:?}");
+
+        // a transaction is never split, regardless of its atomicity
+        self.dispatch_atomic_request(
+            transaction.get_requests(),
+            transaction.get_atomicity(),
+            false,
+        )
+        .await?;
+
+        Ok(())
+    }
+
     /// Executes a table operation.
     ///
     /// See [TableOperation](crate::table::TableOperation) for supported operations, like synchronization of counters or registers.
@@ -664,53 +899,450 @@ impl SwitchConnection {
     }
 
     /// Retrieves the entries of multiple tables.
+    ///
+    /// All response messages of the switch are consumed, so large tables are returned completely.
+    /// See [stream_tables_entries](crate::SwitchConnection::stream_tables_entries) to process the entries without buffering them.
     pub async fn get_tables_entries(
         &self,
         requests: Vec<Request>,
     ) -> Result<Vec<TableEntry>, RBFRTError> {
-        let mut veq_req = vec![];
+        let mut stream = self.read_stream(requests).await?;
         let mut entries = vec![];
 
-        for req in requests {
-            veq_req.push(req.request_type(RequestType::Read));
+        while let Some(message) = stream.message().await? {
+            entries.append(&mut self.parse_read_response(message)?);
         }
 
-        match self.dispatch_request(&veq_req).await? {
-            DispatchResult::ReadResult { response } => {
-                let mut stream = response.into_inner();
-                let message = stream.message().await?.unwrap();
-                for entity in message.entities {
-                    let entity = entity.entity.unwrap();
-
-                    match &entity {
-                        Entity::TableEntry(table_entry) => {
-                            let table = self
-                                .bfrt_info
-                                .as_ref()
-                                .unwrap()
-                                .table_get_by_id(table_entry.table_id)?;
-
-                            let entry = table.parse_read_request(entity, table.name())?;
-
-                            entries.push(entry);
-                        }
-                        _ => {
-                            return Err(UnknownReadResult {});
-                        }
-                    }
+        Ok(entries)
+    }
+
+    /// Retrieves the entries of a single table as a [Stream](futures_core::Stream).
+    ///
+    /// See [stream_tables_entries](crate::SwitchConnection::stream_tables_entries).
+    pub async fn stream_table_entries(
+        &self,
+        request: Request,
+    ) -> Result<TableEntryStream<'_>, RBFRTError> {
+        self.stream_tables_entries(vec![request]).await
+    }
+
+    /// Retrieves the entries of multiple tables as a [Stream](futures_core::Stream).
+    ///
+    /// Entries are parsed as the response messages of the switch arrive, so large tables can be processed without buffering all entries.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rbfrt::SwitchConnection;
+    /// use rbfrt::table::Request;
+    /// use tokio_stream::StreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let switch = SwitchConnection::builder("localhost", 50052)
+    ///         .device_id(0)
+    ///         .client_id(1)
+    ///         .p4_name("my_p4_program")
+    ///         .connect()
+    ///         .await?;
+    ///
+    ///     let mut entries = switch
+    ///         .stream_tables_entries(vec![Request::new("ingress.ipv4_lpm")])
+    ///         .await?;
+    ///
+    ///     while let Some(entry) = entries.next().await {
+    ///         println!("{:?}", entry?);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn stream_tables_entries(
+        &self,
+        requests: Vec<Request>,
+    ) -> Result<TableEntryStream<'_>, RBFRTError> {
+        let mut stream = self.read_stream(requests).await?;
+
+        Ok(Box::pin(try_stream! {
+            while let Some(message) = stream.message().await? {
+                for entry in self.parse_read_response(message)? {
+                    yield entry;
                 }
-                // This drains all remanining messages in the stream.
-                // In the bfrt, we only get a single message, so this shouldnt be a problem.
-                // However, without doing this, the stream will not be closed and the connection will hang.
-                let _ = stream.trailers().await;
-                Ok(entries)
             }
+        }))
+    }
+
+    /// Dispatches the read `requests` and returns the stream of response messages.
+    async fn read_stream(
+        &self,
+        requests: Vec<Request>,
+    ) -> Result<Streaming<ReadResponse>, RBFRTError> {
+        let veq_req: Vec<Request> = requests
+            .into_iter()
+            .map(|req| req.request_type(RequestType::Read))
+            .collect();
+
+        match self.dispatch_request(&veq_req).await? {
+            DispatchResult::ReadResult { response } => Ok(response.into_inner()),
             _ => {
                 panic!("Unreachable code.")
             }
         }
     }
 
+    /// Parses the table entries of a single response message.
+    fn parse_read_response(&self, message: ReadResponse) -> Result<Vec<TableEntry>, RBFRTError> {
+        let mut entries = vec![];
+
+        for entity in message.entities {
+            let entity = entity.entity.unwrap();
+
+            match &entity {
+                Entity::TableEntry(table_entry) => {
+                    let table = self
+                        .bfrt_info
+                        .as_ref()
+                        .unwrap()
+                        .table_get_by_id(table_entry.table_id)?;
+
+                    let entry = table.parse_read_request(entity, table.name())?;
+
+                    entries.push(entry);
+                }
+                _ => {
+                    return Err(UnknownReadResult {});
+                }
+            }
+        }
+
+        Ok(entries)
+    }
+
+    /// Retrieves the handle of the entry matching the match keys of the `request`.
+    ///
+    /// The handle can be used to address the entry with [Request::handle](crate::table::Request::handle).
+    /// This avoids the key lookup on the switch, e.g., for frequent updates of the same entry.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rbfrt::{SwitchConnection, table};
+    /// use rbfrt::table::MatchValue;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let switch = SwitchConnection::builder("localhost", 50052)
+    ///         .device_id(0)
+    ///         .client_id(1)
+    ///         .p4_name("my_p4_program")
+    ///         .connect()
+    ///         .await?;
+    ///
+    ///     let req = table::Request::new("ingress.exact_forward")
+    ///         .match_key("ig_intr_md.ingress_port", MatchValue::exact(10));
+    ///
+    ///     let handle = switch.get_entry_handle(req).await?;
+    ///
+    ///     for port in 0..10 {
+    ///         let update = table::Request::new("ingress.exact_forward")
+    ///             .handle(handle)
+    ///             .action("ingress.do_forward")
+    ///             .action_data("e_port", port);
+    ///
+    ///         switch.update_table_entry(update).await?;
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_entry_handle(&self, request: Request) -> Result<u32, RBFRTError> {
+        let mut handles = self.get_entry_handles(vec![request]).await?;
+
+        match handles.pop() {
+            Some(handle) => Ok(handle),
+            None => Err(UnknownReadResult {}),
+        }
+    }
+
+    /// Retrieves the handles of multiple entries.
+    ///
+    /// The handles are returned in the order of the `requests`.
+    /// See [get_entry_handle](crate::SwitchConnection::get_entry_handle).
+    pub async fn get_entry_handles(&self, requests: Vec<Request>) -> Result<Vec<u32>, RBFRTError> {
+        debug!("Read entry handles {requests:?}");
+        let bfrt_info = self.bfrt_info.as_ref().unwrap();
+
+        self.check_entry_scopes(&requests).await?;
+
+        let mut entities = vec![];
+
+        for req in &requests {
+            entities.push(
+                bfrt_info
+                    .table_get(req.get_table_name())?
+                    .build_handle_request(req)?,
+            );
+        }
+
+        let mut handles = vec![];
+
+        for entity in self
+            .read_entities(entities, self.get_target_device())
+            .await?
+        {
+            match entity {
+                Entity::Handle(bfrt_proto::HandleId {
+                    value: Some(bfrt_proto::handle_id::Value::HandleId(handle)),
+                    ..
+                }) => handles.push(handle),
+                _ => return Err(UnknownReadResult {}),
+            }
+        }
+
+        if handles.len() != requests.len() {
+            return Err(UnknownReadResult {});
+        }
+
+        Ok(handles)
+    }
+
+    /// Resolves the id of an object by its name on the switch.
+    ///
+    /// In contrast to the other functions, the id is not taken from the cached schema but reported by the switch.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rbfrt::SwitchConnection;
+    /// use rbfrt::object::ObjectName;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let switch = SwitchConnection::builder("localhost", 50052)
+    ///         .device_id(0)
+    ///         .client_id(1)
+    ///         .p4_name("my_p4_program")
+    ///         .connect()
+    ///         .await?;
+    ///
+    ///     let id = switch
+    ///         .resolve_object_id(ObjectName::table("pipe.ingress.exact_forward"))
+    ///         .await?;
+    ///
+    ///     println!("Table id: {id}");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn resolve_object_id(&self, name: ObjectName) -> Result<u32, RBFRTError> {
+        let mut ids = self.resolve_object_ids(vec![name]).await?;
+
+        match ids.pop() {
+            Some(id) => Ok(id),
+            None => Err(UnknownReadResult {}),
+        }
+    }
+
+    /// Resolves the ids of multiple objects by their names on the switch.
+    ///
+    /// The ids are returned in the order of the `names`.
+    /// See [resolve_object_id](crate::SwitchConnection::resolve_object_id).
+    pub async fn resolve_object_ids(&self, names: Vec<ObjectName>) -> Result<Vec<u32>, RBFRTError> {
+        debug!("Resolve object ids {names:?}");
+        let entities = names
+            .iter()
+            .map(|n| bfrt_proto::Entity {
+                entity: Some(Entity::ObjectId(n.to_proto())),
+            })
+            .collect();
+
+        let mut ids = vec![];
+
+        for entity in self
+            .read_entities(entities, self.get_target_device())
+            .await?
+        {
+            match entity {
+                Entity::ObjectId(o) => ids.push(o.id),
+                _ => return Err(UnknownReadResult {}),
+            }
+        }
+
+        if ids.len() != names.len() {
+            return Err(UnknownReadResult {});
+        }
+
+        Ok(ids)
+    }
+
+    /// Retrieves the current occupancy of a table.
+    ///
+    /// See [capacity_report](crate::SwitchConnection::capacity_report) for the occupancy of all P4 tables.
+    pub async fn get_table_usage(&self, name: &str) -> Result<TableUsage, RBFRTError> {
+        let mut usage = self.get_tables_usage(vec![name]).await?;
+
+        match usage.pop() {
+            Some(usage) => Ok(usage),
+            None => Err(UnknownReadResult {}),
+        }
+    }
+
+    /// Retrieves the current occupancy of multiple tables.
+    pub async fn get_tables_usage(&self, names: Vec<&str>) -> Result<Vec<TableUsage>, RBFRTError> {
+        debug!("Read table usage : {names:?}");
+        self.read_tables_usage(&names, None).await
+    }
+
+    /// Retrieves the current occupancy of a table in each of the given `pipes`.
+    ///
+    /// This is only useful for asymmetric tables whose entries differ per pipe.
+    pub async fn get_table_usage_per_pipe(
+        &self,
+        name: &str,
+        pipes: Vec<u32>,
+    ) -> Result<Vec<TableUsage>, RBFRTError> {
+        debug!("Read table usage per pipe : {name} {pipes:?}");
+        let mut usage = vec![];
+
+        for pipe in pipes {
+            usage.append(&mut self.read_tables_usage(&[name], Some(pipe)).await?);
+        }
+
+        Ok(usage)
+    }
+
+    /// Retrieves the current occupancy of all match-action tables of the P4 program.
+    ///
+    /// The tables are sorted by their utilization in descending order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rbfrt::SwitchConnection;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let switch = SwitchConnection::builder("localhost", 50052)
+    ///         .device_id(0)
+    ///         .client_id(1)
+    ///         .p4_name("my_p4_program")
+    ///         .connect()
+    ///         .await?;
+    ///
+    ///     for usage in switch.capacity_report().await? {
+    ///         if usage.utilization() > 0.9 {
+    ///             println!("{} is almost full: {}/{}", usage.table_name, usage.usage, usage.size);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn capacity_report(&self) -> Result<Vec<TableUsage>, RBFRTError> {
+        let names: Vec<String> = self
+            .bfrt_info
+            .as_ref()
+            .unwrap()
+            .get_tables()
+            .iter()
+            .filter(|t| {
+                matches!(
+                    t.get_table_type(),
+                    TableType::MatchActionDirect
+                        | TableType::MatchActionIndirect
+                        | TableType::MatchActionIndirectSelector
+                )
+            })
+            .map(|t| t.name().to_owned())
+            .collect();
+
+        if names.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut usage = self
+            .get_tables_usage(names.iter().map(|n| n.as_str()).collect())
+            .await?;
+
+        usage.sort_by(|a, b| b.utilization().total_cmp(&a.utilization()));
+
+        Ok(usage)
+    }
+
+    /// Reads the usage of the tables with the given `names`, optionally restricted to a single `pipe`.
+    async fn read_tables_usage(
+        &self,
+        names: &[&str],
+        pipe: Option<u32>,
+    ) -> Result<Vec<TableUsage>, RBFRTError> {
+        let bfrt_info = self.bfrt_info.as_ref().unwrap();
+
+        let mut entities = vec![];
+
+        for name in names {
+            entities.push(bfrt_info.table_get(name)?.build_usage_request());
+        }
+
+        let mut target = self.get_target_device();
+
+        if let Some(pipe) = pipe {
+            target.pipe_id = pipe;
+        }
+
+        let mut usage = vec![];
+
+        for entity in self.read_entities(entities, target).await? {
+            match entity {
+                Entity::TableUsage(u) => {
+                    let table = bfrt_info.table_get_by_id(u.table_id)?;
+
+                    usage.push(TableUsage {
+                        table_id: u.table_id,
+                        table_name: table.name().to_owned(),
+                        pipe,
+                        usage: u.usage,
+                        size: table.size(),
+                    });
+                }
+                _ => return Err(UnknownReadResult {}),
+            }
+        }
+
+        Ok(usage)
+    }
+
+    /// Reads the `entities` from the `target` and returns all entities of the response.
+    async fn read_entities(
+        &self,
+        entities: Vec<bfrt_proto::Entity>,
+        target: TargetDevice,
+    ) -> Result<Vec<Entity>, RBFRTError> {
+        if entities.is_empty() {
+            return Err(RequestEmpty {});
+        }
+
+        let req = ReadRequest {
+            target: Some(target),
+            client_id: self.client_id,
+            entities,
+            p4_name: self.p4_name.as_ref().unwrap().to_owned(),
+        };
+
+        let mut client = { self.bf_client.lock().await.clone() };
+        let mut stream = client.read(req).await?.into_inner();
+        let mut result = vec![];
+
+        while let Some(message) = stream.message().await? {
+            for entity in message.entities {
+                if let Some(entity) = entity.entity {
+                    result.push(entity);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
     /// Writes a single entry into a table.
     ///
     /// The entry's key must not be present in the table to insert the new entry.
@@ -730,6 +1362,9 @@ impl SwitchConnection {
     ///
     /// The entries' keys must not be present in the table to insert the new entries.
     /// See [update_table_entries](crate::SwitchConnection::update_table_entries) to update existing entries.
+    ///
+    /// If single requests of the batch fail, a [BatchError](RBFRTError::BatchError) lists the index, table, key, and error of each failed request.
+    /// Batches that exceed the [batch_message_size](crate::SwitchConnectionBuilder::batch_message_size) are split into multiple write requests.
     pub async fn write_table_entries(&self, requests: Vec<Request>) -> Result<(), RBFRTError> {
         debug!("Write table entry {requests:?}");
         let req = requests
@@ -741,6 +1376,25 @@ impl SwitchConnection {
         Ok(())
     }
 
+    /// Writes entries into one or multiple tables with the given [Atomicity].
+    ///
+    /// See [write_table_entries](crate::SwitchConnection::write_table_entries).
+    /// Returns [AtomicityNotSupported](RBFRTError::AtomicityNotSupported) if the switch rejects the requested [Atomicity].
+    pub async fn write_table_entries_atomic(
+        &self,
+        requests: Vec<Request>,
+        atomicity: Atomicity,
+    ) -> Result<(), RBFRTError> {
+        debug!("Write table entry {requests:?} with atomicity {atomicity:?}");
+        let req = requests
+            .iter()
+            .map(|x| x.clone().request_type(RequestType::Write))
+            .collect();
+        self.dispatch_atomic_request(&req, atomicity, true).await?;
+
+        Ok(())
+    }
+
     /// Updates a single entry in a table.
     ///
     /// The entry's key must be present in the table to update the entry.
@@ -758,6 +1412,8 @@ impl SwitchConnection {
     ///
     /// The entries' keys must be present in the tables to update the entries.
     /// See [write_table_entries](crate::SwitchConnection::write_table_entries) to insert new entries.
+    ///
+    /// Failed requests are reported as described in [write_table_entries](crate::SwitchConnection::write_table_entries).
     pub async fn update_table_entries(&self, requests: Vec<Request>) -> Result<(), RBFRTError> {
         debug!("Update table entry {requests:?}");
         let req = requests
@@ -769,175 +1425,1973 @@ impl SwitchConnection {
         Ok(())
     }
 
-    /// Deletes a entry in a table.
+    /// Updates multiple entries in one or multiple tables with the given [Atomicity].
     ///
-    /// See [clear_table](crate::SwitchConnection::clear_table) to delete all entries inside the table.
-    pub async fn delete_table_entry(&self, request: Request) -> Result<(), RBFRTError> {
-        debug!("Delete table entry {request:?}");
-        let req = request.request_type(RequestType::Delete);
-
-        let vec_req = vec![req];
-
-        self.dispatch_request(&vec_req).await?;
+    /// See [update_table_entries](crate::SwitchConnection::update_table_entries).
+    /// Returns [AtomicityNotSupported](RBFRTError::AtomicityNotSupported) if the switch rejects the requested [Atomicity].
+    pub async fn update_table_entries_atomic(
+        &self,
+        requests: Vec<Request>,
+        atomicity: Atomicity,
+    ) -> Result<(), RBFRTError> {
+        debug!("Update table entry {requests:?} with atomicity {atomicity:?}");
+        let req = requests
+            .iter()
+            .map(|x| x.clone().request_type(RequestType::Update))
+            .collect();
+        self.dispatch_atomic_request(&req, atomicity, true).await?;
+
+        Ok(())
+    }
+
+    /// Inserts or updates a single entry in a table.
+    ///
+    /// The entry is inserted if its key is not present in the table, otherwise the existing entry is updated.
+    /// See [write_table_entry](crate::SwitchConnection::write_table_entry) and [update_table_entry](crate::SwitchConnection::update_table_entry) for the strict variants.
+    pub async fn upsert_table_entry(&self, request: Request) -> Result<(), RBFRTError> {
+        debug!("Upsert table entry {request:?}");
+        let req = request.request_type(RequestType::Upsert);
+        let vec_req = vec![req];
+        self.dispatch_request(&vec_req).await?;
+
+        Ok(())
+    }
+
+    /// Inserts or updates multiple entries in one or multiple tables.
+    ///
+    /// Entries whose keys are not present in the tables are inserted, all others are updated.
+    /// See [write_table_entries](crate::SwitchConnection::write_table_entries) and [update_table_entries](crate::SwitchConnection::update_table_entries) for the strict variants.
+    pub async fn upsert_table_entries(&self, requests: Vec<Request>) -> Result<(), RBFRTError> {
+        debug!("Upsert table entry {requests:?}");
+        let req = requests
+            .iter()
+            .map(|x| x.clone().request_type(RequestType::Upsert))
+            .collect();
+        self.dispatch_request(&req).await?;
+
+        Ok(())
+    }
+
+    /// Incrementally adds the data of the [Request] to an existing entry.
+    ///
+    /// Only the provided values of repeated data fields are appended to the entry, e.g., a single member `$ACTION_MEMBER_ID` of an action selector group.
+    /// The entry's key must be present in the table.
+    /// See [delete_from_table_entry](crate::SwitchConnection::delete_from_table_entry) to remove data incrementally.
+    pub async fn add_to_table_entry(&self, request: Request) -> Result<(), RBFRTError> {
+        debug!("Modify inc add table entry {request:?}");
+        let req = request.request_type(RequestType::ModifyIncAdd);
+        let vec_req = vec![req];
+        self.dispatch_request(&vec_req).await?;
+
+        Ok(())
+    }
+
+    /// Incrementally adds the data of multiple [Requests](Request) to existing entries.
+    ///
+    /// See [add_to_table_entry](crate::SwitchConnection::add_to_table_entry).
+    pub async fn add_to_table_entries(&self, requests: Vec<Request>) -> Result<(), RBFRTError> {
+        debug!("Modify inc add table entry {requests:?}");
+        let req = requests
+            .iter()
+            .map(|x| x.clone().request_type(RequestType::ModifyIncAdd))
+            .collect();
+        self.dispatch_request(&req).await?;
+
+        Ok(())
+    }
+
+    /// Incrementally removes the data of the [Request] from an existing entry.
+    ///
+    /// Only the provided values of repeated data fields are removed from the entry, e.g., a single member `$ACTION_MEMBER_ID` of an action selector group.
+    /// The entry's key must be present in the table.
+    /// See [add_to_table_entry](crate::SwitchConnection::add_to_table_entry) to add data incrementally.
+    pub async fn delete_from_table_entry(&self, request: Request) -> Result<(), RBFRTError> {
+        debug!("Modify inc delete table entry {request:?}");
+        let req = request.request_type(RequestType::ModifyIncDelete);
+        let vec_req = vec![req];
+        self.dispatch_request(&vec_req).await?;
+
+        Ok(())
+    }
+
+    /// Incrementally removes the data of multiple [Requests](Request) from existing entries.
+    ///
+    /// See [delete_from_table_entry](crate::SwitchConnection::delete_from_table_entry).
+    pub async fn delete_from_table_entries(
+        &self,
+        requests: Vec<Request>,
+    ) -> Result<(), RBFRTError> {
+        debug!("Modify inc delete table entry {requests:?}");
+        let req = requests
+            .iter()
+            .map(|x| x.clone().request_type(RequestType::ModifyIncDelete))
+            .collect();
+        self.dispatch_request(&req).await?;
+
+        Ok(())
+    }
+
+    /// Deletes a entry in a table.
+    ///
+    /// See [clear_table](crate::SwitchConnection::clear_table) to delete all entries inside the table.
+    pub async fn delete_table_entry(&self, request: Request) -> Result<(), RBFRTError> {
+        debug!("Delete table entry {request:?}");
+        let req = request.request_type(RequestType::Delete);
+
+        let vec_req = vec![req];
+
+        self.dispatch_request(&vec_req).await?;
 
         Ok(())
     }
 
     /// Deletes multiple entries in one or multiple tables.
     ///
-    /// See [clear_tables](crate::SwitchConnection::clear_tables) to delete all entries inside the tables.
-    pub async fn delete_table_entries(&self, request: Vec<Request>) -> Result<(), RBFRTError> {
-        debug!("Delete table entries {request:?}");
-        let vec_req = request
+    /// See [clear_tables](crate::SwitchConnection::clear_tables) to delete all entries inside the tables.
+    ///
+    /// Failed requests are reported as described in [write_table_entries](crate::SwitchConnection::write_table_entries).
+    pub async fn delete_table_entries(&self, request: Vec<Request>) -> Result<(), RBFRTError> {
+        debug!("Delete table entries {request:?}");
+        let vec_req = request
+            .iter()
+            .map(|x| x.clone().request_type(RequestType::Delete))
+            .collect();
+
+        self.dispatch_request(&vec_req).await?;
+
+        Ok(())
+    }
+
+    /// Deletes multiple entries in one or multiple tables with the given [Atomicity].
+    ///
+    /// See [delete_table_entries](crate::SwitchConnection::delete_table_entries).
+    /// Returns [AtomicityNotSupported](RBFRTError::AtomicityNotSupported) if the switch rejects the requested [Atomicity].
+    pub async fn delete_table_entries_atomic(
+        &self,
+        requests: Vec<Request>,
+        atomicity: Atomicity,
+    ) -> Result<(), RBFRTError> {
+        debug!("Delete table entries {requests:?} with atomicity {atomicity:?}");
+        let vec_req = requests
+            .iter()
+            .map(|x| x.clone().request_type(RequestType::Delete))
+            .collect();
+
+        self.dispatch_atomic_request(&vec_req, atomicity, true)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Deletes all entries in a table.
+    ///
+    /// See [delete_table_entry](crate::SwitchConnection::delete_table_entry) or [delete_table_entries](crate::SwitchConnection::delete_table_entries) to delete one or multiple entries inside the table.
+    /// The default entry is kept, see [reset_table](crate::SwitchConnection::reset_table) to restore it as well.
+    pub async fn clear_table(&self, name: &str) -> Result<(), RBFRTError> {
+        debug!("Clear table : {name}");
+        let req = Request::new(name);
+
+        self.delete_table_entry(req).await?;
+
+        Ok(())
+    }
+
+    /// Deletes all entries in multiple tables.
+    ///
+    /// See [delete_table_entry](crate::SwitchConnection::delete_table_entry) or [delete_table_entries](crate::SwitchConnection::delete_table_entries) to delete one or multiple entries inside the tables.
+    pub async fn clear_tables(&self, name: Vec<&str>) -> Result<(), RBFRTError> {
+        debug!("Clear tables : {name:?}");
+        let reqs: Vec<Request> = name.iter().map(|x| Request::new(x)).collect();
+
+        self.delete_table_entries(reqs).await?;
+
+        Ok(())
+    }
+
+    /// Resets a table to its initial state.
+    ///
+    /// In contrast to [clear_table](crate::SwitchConnection::clear_table), which only deletes the entries of a match-action table, this also restores the table's default entry to the default action of the P4 program.
+    /// Tables with fixed indices, e.g., registers or indirect counters, are reset to their initial values.
+    pub async fn reset_table(&self, name: &str) -> Result<(), RBFRTError> {
+        debug!("Reset table : {name}");
+        let req = Request::new(name).request_type(RequestType::Reset);
+
+        self.dispatch_request(&vec![req]).await?;
+
+        Ok(())
+    }
+
+    /// Reads the current default entry of a table.
+    ///
+    /// The returned [TableEntry] contains the default action and its action data.
+    pub async fn get_default_entry(&self, name: &str) -> Result<TableEntry, RBFRTError> {
+        debug!("Read default entry : {name}");
+        let req = Request::new(name).default(true);
+
+        let mut entries = self.get_table_entries(req).await?;
+
+        match entries.pop() {
+            Some(entry) => Ok(entry),
+            None => Err(UnknownReadResult {}),
+        }
+    }
+
+    /// Sets the default entry of a table.
+    ///
+    /// The `request` specifies the table, the action and the action data of the default entry.
+    /// Match keys are not allowed for the default entry.
+    ///
+    /// Returns [DefaultEntryMatchKeys](RBFRTError::DefaultEntryMatchKeys) if the request has match keys,
+    /// [ConstDefaultAction](RBFRTError::ConstDefaultAction) if the default action of the table is const,
+    /// and [InvalidDefaultAction](RBFRTError::InvalidDefaultAction) if the action may not be used as default action.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rbfrt::{SwitchConnection, table};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let switch = SwitchConnection::builder("localhost", 50052)
+    ///         .device_id(0)
+    ///         .client_id(1)
+    ///         .p4_name("my_p4_program")
+    ///         .connect()
+    ///         .await?;
+    ///
+    ///     let req = table::Request::new("ingress.exact_forward")
+    ///         .action("ingress.do_forward")
+    ///         .action_data("e_port", 20);
+    ///
+    ///     switch.set_default_entry(req).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn set_default_entry(&self, request: Request) -> Result<(), RBFRTError> {
+        debug!("Set default entry {request:?}");
+        let table = self
+            .bfrt_info
+            .as_ref()
+            .unwrap()
+            .table_get(request.get_table_name())?;
+
+        if !request.get_match_keys().is_empty() {
+            return Err(DefaultEntryMatchKeys {
+                table_name: table.name().to_owned(),
+            });
+        }
+
+        if table.has_const_default_action() {
+            return Err(ConstDefaultAction {
+                table_name: table.name().to_owned(),
+            });
+        }
+
+        if !table
+            .get_action_by_name(request.get_action_name())?
+            .is_default_allowed()
+        {
+            return Err(InvalidDefaultAction {
+                action_name: request.get_action_name().to_owned(),
+                table_name: table.name().to_owned(),
+            });
+        }
+
+        // the default entry always exists and can only be modified
+        let req = request.default(true).request_type(RequestType::Update);
+
+        self.dispatch_request(&vec![req]).await?;
+
+        Ok(())
+    }
+
+    /// Resets the default entry of a table to the default action of the P4 program.
+    ///
+    /// The regular entries of the table are not changed.
+    /// See [reset_table](crate::SwitchConnection::reset_table) to reset the whole table.
+    ///
+    /// Returns [ConstDefaultAction](RBFRTError::ConstDefaultAction) if the default action of the table is const.
+    pub async fn reset_default_entry(&self, name: &str) -> Result<(), RBFRTError> {
+        debug!("Reset default entry : {name}");
+        let table = self.bfrt_info.as_ref().unwrap().table_get(name)?;
+
+        if table.has_const_default_action() {
+            return Err(ConstDefaultAction {
+                table_name: table.name().to_owned(),
+            });
+        }
+
+        let req = Request::new(name)
+            .default(true)
+            .request_type(RequestType::Reset);
+
+        self.dispatch_request(&vec![req]).await?;
+
+        Ok(())
+    }
+
+    /// Resets multiple tables to their initial state.
+    ///
+    /// See [reset_table](crate::SwitchConnection::reset_table) for the difference to [clear_tables](crate::SwitchConnection::clear_tables).
+    pub async fn reset_tables(&self, name: Vec<&str>) -> Result<(), RBFRTError> {
+        debug!("Reset tables : {name:?}");
+        let reqs: Vec<Request> = name
+            .iter()
+            .map(|x| Request::new(x).request_type(RequestType::Reset))
+            .collect();
+
+        self.dispatch_request(&reqs).await?;
+
+        Ok(())
+    }
+
+    /// Enables idle timeout for a table.
+    ///
+    /// The table has to be declared with `idle_timeout = true` in the P4 program.
+    /// In [Notify](crate::table::IdleTableMode::Notify) mode, the TTL of the entries is set with [Request::entry_ttl](crate::table::Request::entry_ttl)
+    /// and the switch sends [IdleTimeoutEvents](IdleTimeoutEvent) to the [idle_timeout_queue](crate::SwitchConnection::idle_timeout_queue).
+    /// In [Poll](crate::table::IdleTableMode::Poll) mode, the hit state is read with [TableEntry::get_entry_hit_state](crate::table::TableEntry::get_entry_hit_state).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rbfrt::{SwitchConnection, table};
+    /// use rbfrt::table::{IdleTable, IdleTableMode, MatchValue};
+    /// use rbfrt::util::IdleTimeoutEvent;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let switch = SwitchConnection::builder("localhost", 50052)
+    ///         .device_id(0)
+    ///         .client_id(1)
+    ///         .p4_name("my_p4_program")
+    ///         .connect()
+    ///         .await?;
+    ///
+    ///     let idle_table = IdleTable::new(IdleTableMode::Notify)
+    ///         .ttl_query_interval(1000)
+    ///         .min_ttl(1000)
+    ///         .max_ttl(60000);
+    ///
+    ///     switch.enable_idle_table("ingress.exact_forward", idle_table).await?;
+    ///
+    ///     let req = table::Request::new("ingress.exact_forward")
+    ///         .match_key("ig_intr_md.ingress_port", MatchValue::exact(10))
+    ///         .action("ingress.do_forward")
+    ///         .action_data("e_port", 20)
+    ///         .entry_ttl(5000);
+    ///
+    ///     switch.write_table_entry(req).await?;
+    ///
+    ///     while let Ok(event) = switch.idle_timeout_queue.recv() {
+    ///         if let IdleTimeoutEvent::EntryIdle { table_name, match_keys } = event {
+    ///             println!("Entry {match_keys:?} of {table_name} is idle");
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn enable_idle_table(
+        &self,
+        name: &str,
+        idle_table: IdleTable,
+    ) -> Result<(), RBFRTError> {
+        debug!("Enable idle table : {name} {idle_table:?}");
+        self.write_table_attribute(name, Attribute::IdleTable(idle_table.to_proto()))
+            .await
+    }
+
+    /// Disables idle timeout for a table.
+    pub async fn disable_idle_table(&self, name: &str) -> Result<(), RBFRTError> {
+        debug!("Disable idle table : {name}");
+        self.write_table_attribute(name, Attribute::IdleTable(IdleTable::disabled().to_proto()))
+            .await
+    }
+
+    /// Retrieves the idle timeout configuration of a table.
+    pub async fn get_idle_table(&self, name: &str) -> Result<IdleTable, RBFRTError> {
+        debug!("Read idle table : {name}");
+        match self
+            .read_table_attribute(name, Attribute::IdleTable(Default::default()))
+            .await?
+        {
+            Attribute::IdleTable(idle_table) => Ok(IdleTable::from_proto(&idle_table)),
+            _ => Err(UnknownReadResult {}),
+        }
+    }
+
+    /// Sets the interval in milliseconds in which the switch synchronizes the hit state or counters of a table from the hardware.
+    pub async fn set_state_pull_interval(
+        &self,
+        name: &str,
+        interval_ms: u32,
+    ) -> Result<(), RBFRTError> {
+        debug!("Set state pull interval : {name} {interval_ms}");
+        self.write_table_attribute(
+            name,
+            Attribute::IntvlMs(bfrt_proto::StatePullIntvl {
+                intvl_val: interval_ms,
+            }),
+        )
+        .await
+    }
+
+    /// Sets the entry scope of a table.
+    ///
+    /// The table has to be empty to change its entry scope.
+    /// Entries of tables with an asymmetric pipe scope can be addressed to a single pipe with [Request::pipe](crate::table::Request::pipe).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rbfrt::{SwitchConnection, table};
+    /// use rbfrt::table::{EntryScope, MatchValue, ScopeMode};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let switch = SwitchConnection::builder("localhost", 50052)
+    ///         .device_id(0)
+    ///         .client_id(1)
+    ///         .p4_name("my_p4_program")
+    ///         .connect()
+    ///         .await?;
+    ///
+    ///     let scope = EntryScope::new().pipe_scope(ScopeMode::Single);
+    ///
+    ///     switch.set_entry_scope("ingress.exact_forward", scope).await?;
+    ///
+    ///     let req = table::Request::new("ingress.exact_forward")
+    ///         .match_key("ig_intr_md.ingress_port", MatchValue::exact(10))
+    ///         .action("ingress.do_forward")
+    ///         .action_data("e_port", 20)
+    ///         .pipe(1);
+    ///
+    ///     switch.write_table_entry(req).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn set_entry_scope(&self, name: &str, scope: EntryScope) -> Result<(), RBFRTError> {
+        debug!("Set entry scope : {name} {scope:?}");
+        let table = self.bfrt_info.as_ref().unwrap().table_get(name)?;
+
+        self.write_table_attribute(name, Attribute::EntryScope(scope.to_proto()))
+            .await?;
+
+        self.entry_scopes.write().unwrap().insert(table.id(), scope);
+
+        Ok(())
+    }
+
+    /// Retrieves the entry scope of a table.
+    pub async fn get_entry_scope(&self, name: &str) -> Result<EntryScope, RBFRTError> {
+        debug!("Read entry scope : {name}");
+        let table = self.bfrt_info.as_ref().unwrap().table_get(name)?;
+
+        match self
+            .read_table_attribute(name, Attribute::EntryScope(Default::default()))
+            .await?
+        {
+            Attribute::EntryScope(scope) => {
+                let scope = EntryScope::from_proto(&scope);
+
+                self.entry_scopes.write().unwrap().insert(table.id(), scope);
+
+                Ok(scope)
+            }
+            _ => Err(UnknownReadResult {}),
+        }
+    }
+
+    /// Verifies that requests addressed to a single pipe or gress only refer to tables with an asymmetric scope in that dimension.
+    ///
+    /// The target of a request is resolved from the request and the connection's default target.
+    /// The entry scope of a table is read from the switch once and cached afterwards.
+    /// Tables without the `EntryScope` attribute are not checked.
+    async fn check_entry_scopes(&self, requests: &[Request]) -> Result<(), RBFRTError> {
+        let bfrt_info = self.bfrt_info.as_ref().unwrap();
+
+        for req in requests {
+            let target = build_entry_target(req, &self.target).unwrap_or(self.target);
+            let direction = Direction::from_proto(target.direction);
+
+            if target.pipe_id == ALL_PIPES && direction == Direction::All {
+                continue;
+            }
+
+            let table = bfrt_info.table_get(req.get_table_name())?;
+
+            // tables without a configurable entry scope are addressed per pipe as they are
+            if !table.attributes().iter().any(|a| a == "EntryScope") {
+                continue;
+            }
+
+            let cached = self.entry_scopes.read().unwrap().get(&table.id()).copied();
+
+            let scope = match cached {
+                Some(scope) => scope,
+                None => self.get_entry_scope(req.get_table_name()).await?,
+            };
+
+            if target.pipe_id != ALL_PIPES && !scope.is_pipe_asymmetric() {
+                return Err(SymmetricTable {
+                    table_name: table.name().to_owned(),
+                    pipe: target.pipe_id,
+                });
+            }
+
+            if direction != Direction::All && !scope.is_gress_asymmetric() {
+                return Err(GressSymmetricTable {
+                    table_name: table.name().to_owned(),
+                    direction,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the attributes the schema declares for a table, e.g., `EntryScope` or `DynamicKeyMask`.
+    pub fn get_table_attributes(&self, name: &str) -> Result<Vec<String>, RBFRTError> {
+        let table = self.bfrt_info.as_ref().unwrap().table_get(name)?;
+
+        Ok(table.attributes().to_vec())
+    }
+
+    /// Returns the names of the match key fields of a table.
+    ///
+    /// These are the fields that can be masked with a [DynKeyMask].
+    pub fn get_table_key_fields(&self, name: &str) -> Result<Vec<String>, RBFRTError> {
+        let table = self.bfrt_info.as_ref().unwrap().table_get(name)?;
+
+        Ok(table.key_names().iter().map(|k| k.to_string()).collect())
+    }
+
+    /// Returns the names of the hash algorithms the schema declares for a hash table.
+    pub fn get_hash_algorithms(&self, name: &str) -> Result<Vec<String>, RBFRTError> {
+        let table = self.bfrt_info.as_ref().unwrap().table_get(name)?;
+
+        Ok(table.data_field_choices("algorithm_name"))
+    }
+
+    /// Sets the dynamic key mask of a table.
+    ///
+    /// Returns [AttributeNotSupported](RBFRTError::AttributeNotSupported) if the table does not support dynamic key masks.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rbfrt::SwitchConnection;
+    /// use rbfrt::table::DynKeyMask;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let switch = SwitchConnection::builder("localhost", 50052)
+    ///         .device_id(0)
+    ///         .client_id(1)
+    ///         .p4_name("my_p4_program")
+    ///         .connect()
+    ///         .await?;
+    ///
+    ///     // ignore the last byte of the destination address
+    ///     let mask = DynKeyMask::new().mask("hdr.ipv4.dst_addr", vec![255u8, 255, 255, 0]);
+    ///
+    ///     switch.set_dyn_key_mask("ingress.ipv4_exact", mask).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn set_dyn_key_mask(&self, name: &str, mask: DynKeyMask) -> Result<(), RBFRTError> {
+        debug!("Set dynamic key mask : {name} {mask:?}");
+        let table = self.check_table_attribute(name, DYN_KEY_MASK_ATTRIBUTE)?;
+
+        let mask = table.build_dyn_key_mask(&mask)?;
+
+        self.write_table_attribute(name, Attribute::DynKeyMask(mask))
+            .await
+    }
+
+    /// Retrieves the dynamic key mask of a table.
+    pub async fn get_dyn_key_mask(&self, name: &str) -> Result<DynKeyMask, RBFRTError> {
+        debug!("Read dynamic key mask : {name}");
+        let table = self.check_table_attribute(name, DYN_KEY_MASK_ATTRIBUTE)?;
+
+        match self
+            .read_table_attribute(name, Attribute::DynKeyMask(Default::default()))
+            .await?
+        {
+            Attribute::DynKeyMask(mask) => table.parse_dyn_key_mask(&mask),
+            _ => Err(UnknownReadResult {}),
+        }
+    }
+
+    /// Sets the hash algorithm and seed of a hash table.
+    ///
+    /// The handle of the algorithm is used as is, see [get_dyn_hashing](crate::SwitchConnection::get_dyn_hashing).
+    /// An algorithm given by name is mapped to its position in [get_hash_algorithms](crate::SwitchConnection::get_hash_algorithms).
+    /// Returns [AttributeNotSupported](RBFRTError::AttributeNotSupported) if the table does not support dynamic hashing,
+    /// and [UnknownHashAlgorithm](RBFRTError::UnknownHashAlgorithm) if the schema does not declare the algorithm.
+    pub async fn set_dyn_hashing(&self, name: &str, hashing: DynHashing) -> Result<(), RBFRTError> {
+        debug!("Set dynamic hashing : {name} {hashing:?}");
+        let table = self.check_table_attribute(name, DYN_HASHING_ATTRIBUTE)?;
+
+        let alg = match (hashing.get_handle(), hashing.get_algorithm()) {
+            (Some(handle), _) => handle,
+            (None, algorithm) => {
+                let algorithm = algorithm.unwrap_or_default();
+
+                match table
+                    .data_field_choices("algorithm_name")
+                    .iter()
+                    .position(|a| a == algorithm)
+                {
+                    Some(position) => position as u32,
+                    None => {
+                        return Err(UnknownHashAlgorithm {
+                            table_name: table.name().to_owned(),
+                            algorithm: algorithm.to_owned(),
+                        })
+                    }
+                }
+            }
+        };
+
+        self.write_table_attribute(
+            name,
+            Attribute::DynHashing(bfrt_proto::DynHashing {
+                alg,
+                seed: hashing.get_seed(),
+            }),
+        )
+        .await
+    }
+
+    /// Retrieves the hash algorithm and seed of a hash table.
+    ///
+    /// The returned [DynHashing] always contains the handle of the algorithm on the switch,
+    /// and its name if the schema declares an algorithm at that position.
+    pub async fn get_dyn_hashing(&self, name: &str) -> Result<DynHashing, RBFRTError> {
+        debug!("Read dynamic hashing : {name}");
+        let table = self.check_table_attribute(name, DYN_HASHING_ATTRIBUTE)?;
+
+        match self
+            .read_table_attribute(name, Attribute::DynHashing(Default::default()))
+            .await?
+        {
+            Attribute::DynHashing(hashing) => Ok(DynHashing::from_switch(
+                table
+                    .data_field_choices("algorithm_name")
+                    .get(hashing.alg as usize)
+                    .cloned(),
+                hashing.alg,
+                hashing.seed,
+            )),
+            _ => Err(UnknownReadResult {}),
+        }
+    }
+
+    /// Returns the table with the given `name` if its schema declares the `attribute`.
+    ///
+    /// Returns [AttributeNotSupported](RBFRTError::AttributeNotSupported) otherwise.
+    fn check_table_attribute(
+        &self,
+        name: &str,
+        attribute: &str,
+    ) -> Result<&BFRTTableObject, RBFRTError> {
+        let table = self.bfrt_info.as_ref().unwrap().table_get(name)?;
+
+        if !table.attributes().iter().any(|a| a == attribute) {
+            return Err(AttributeNotSupported {
+                table_name: table.name().to_owned(),
+                attribute: attribute.to_owned(),
+            });
+        }
+
+        Ok(table)
+    }
+
+    /// Sets the number of bytes that are added to the packet length by the counters and meters of a table.
+    ///
+    /// A negative `bytes` value excludes, e.g., encapsulation headers from byte counters and meter rates.
+    /// The table has to be a counter or meter table, or a match-action table with a direct counter or meter.
+    /// Returns [AttributeNotSupported](RBFRTError::AttributeNotSupported) otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rbfrt::SwitchConnection;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let switch = SwitchConnection::builder("localhost", 50052)
+    ///         .device_id(0)
+    ///         .client_id(1)
+    ///         .p4_name("my_p4_program")
+    ///         .connect()
+    ///         .await?;
+    ///
+    ///     // ignore the VXLAN encapsulation
+    ///     switch.set_byte_count_adj("ingress.port_meter", -50).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn set_byte_count_adj(&self, name: &str, bytes: i32) -> Result<(), RBFRTError> {
+        debug!("Set byte count adjustment : {name} {bytes}");
+        self.check_byte_count_adj(name)?;
+
+        self.write_table_attribute(
+            name,
+            Attribute::ByteCountAdj(bfrt_proto::ByteCountAdj {
+                byte_count_adjust: bytes,
+            }),
+        )
+        .await
+    }
+
+    /// Retrieves the byte count adjustment of the counters and meters of a table.
+    ///
+    /// See [set_byte_count_adj](crate::SwitchConnection::set_byte_count_adj).
+    pub async fn get_byte_count_adj(&self, name: &str) -> Result<i32, RBFRTError> {
+        debug!("Read byte count adjustment : {name}");
+        self.check_byte_count_adj(name)?;
+
+        match self
+            .read_table_attribute(name, Attribute::ByteCountAdj(Default::default()))
+            .await?
+        {
+            Attribute::ByteCountAdj(adj) => Ok(adj.byte_count_adjust),
+            _ => Err(UnknownReadResult {}),
+        }
+    }
+
+    /// Verifies that the table with the given `name` supports a byte count adjustment.
+    fn check_byte_count_adj(&self, name: &str) -> Result<(), RBFRTError> {
+        let table = self.bfrt_info.as_ref().unwrap().table_get(name)?;
+
+        if !table.supports_byte_count_adj() {
+            return Err(AttributeNotSupported {
+                table_name: table.name().to_owned(),
+                attribute: "ByteCountAdj".to_owned(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Retrieves the device configuration of the packet replication engine.
+    pub async fn get_pre_device_config(&self) -> Result<PreDeviceConfig, RBFRTError> {
+        debug!("Read PRE device config");
+        let name = self.pre_table_name()?;
+
+        match self
+            .read_table_attribute(&name, Attribute::PreDeviceConfig(Default::default()))
+            .await?
+        {
+            Attribute::PreDeviceConfig(config) => Ok(PreDeviceConfig::from_proto(&config)),
+            _ => Err(UnknownReadResult {}),
+        }
+    }
+
+    /// Changes the device configuration of the packet replication engine.
+    ///
+    /// Settings that are not set in the `config` are not changed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rbfrt::SwitchConnection;
+    /// use rbfrt::table::PreDeviceConfig;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let switch = SwitchConnection::builder("localhost", 50052)
+    ///         .device_id(0)
+    ///         .client_id(1)
+    ///         .p4_name("my_p4_program")
+    ///         .connect()
+    ///         .await?;
+    ///
+    ///     let config = PreDeviceConfig::new()
+    ///         .port_protection(true)
+    ///         .fast_failover(true);
+    ///
+    ///     switch.set_pre_device_config(config).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn set_pre_device_config(&self, config: PreDeviceConfig) -> Result<(), RBFRTError> {
+        debug!("Set PRE device config {config:?}");
+        let name = self.pre_table_name()?;
+
+        self.write_table_attribute(&name, Attribute::PreDeviceConfig(config.to_proto()))
+            .await
+    }
+
+    /// Returns the name of the multicast group table that holds the attributes of the packet replication engine.
+    fn pre_table_name(&self) -> Result<String, RBFRTError> {
+        match self
+            .bfrt_info
+            .as_ref()
+            .unwrap()
+            .table_get_by_type(&TableType::PreMgid)
+        {
+            Some(table) => Ok(table.name().to_owned()),
+            None => Err(UnknownTable {
+                table_name: "$pre.mgid".to_owned(),
+            }),
+        }
+    }
+
+    /// Writes the `attribute` of the table with the given `name`.
+    async fn write_table_attribute(
+        &self,
+        name: &str,
+        attribute: Attribute,
+    ) -> Result<(), RBFRTError> {
+        let table = self.bfrt_info.as_ref().unwrap().table_get(name)?;
+
+        let update = bfrt_proto::Update {
+            r#type: 1,
+            entity: Some(table.build_attribute_request(attribute)),
+        };
+
+        let req = self.build_write_request(vec![update], Atomicity::ContinueOnError);
+
+        self.bf_client.lock().await.write(req).await?;
+
+        Ok(())
+    }
+
+    /// Reads the `attribute` of the table with the given `name`.
+    ///
+    /// The value of the `attribute` only selects the kind of the attribute.
+    async fn read_table_attribute(
+        &self,
+        name: &str,
+        attribute: Attribute,
+    ) -> Result<Attribute, RBFRTError> {
+        let table = self.bfrt_info.as_ref().unwrap().table_get(name)?;
+
+        let entities = vec![table.build_attribute_request(attribute)];
+
+        for entity in self
+            .read_entities(entities, self.get_target_device())
+            .await?
+        {
+            if let Entity::TableAttribute(bfrt_proto::TableAttribute {
+                attribute: Some(attribute),
+                ..
+            }) = entity
+            {
+                return Ok(attribute);
+            }
+        }
+
+        Err(UnknownReadResult {})
+    }
+
+    /// Reads the value of a register.
+    pub async fn get_register_entry(
+        &self,
+        request: register::Request,
+    ) -> Result<Register, RBFRTError> {
+        debug!("Read register {request:?}");
+        let mut table_request = register_target(
+            &request,
+            Request::new(request.get_name())
+                .from_hw(request.is_from_hw())
+                .request_type(RequestType::Read),
+        );
+
+        if request.get_index().is_some() {
+            table_request = table_request.match_key(
+                "$REGISTER_INDEX",
+                MatchValue::exact(request.get_index().unwrap()),
+            );
+        }
+
+        let entries = self.get_table_entries(table_request).await?;
+
+        let name = request.get_name();
+
+        Ok(Register::parse_register_entries(entries, name))
+    }
+
+    /// Reads the values of multiple registers or indices.
+    pub async fn get_register_entries(
+        &self,
+        requests: Vec<register::Request>,
+    ) -> Result<Register, RBFRTError> {
+        debug!("Read register {requests:?}");
+
+        let name = requests.first().as_ref().unwrap().get_name();
+
+        let mut req = vec![];
+
+        for request in &requests {
+            let table_request = register_target(
+                request,
+                Request::new(request.get_name())
+                    .from_hw(request.is_from_hw())
+                    .request_type(RequestType::Read),
+            );
+
+            if request.get_index().is_some() {
+                req.push(table_request.match_key(
+                    "$REGISTER_INDEX",
+                    MatchValue::exact(request.get_index().unwrap()),
+                ));
+            }
+        }
+
+        let entries = self.get_tables_entries(req).await?;
+
+        Ok(Register::parse_register_entries(entries, name))
+    }
+
+    /// Writes a value into a register.
+    pub async fn write_register_entry(&self, request: register::Request) -> Result<(), RBFRTError> {
+        debug!("Write register {request:?}");
+        let table_request = self.register_to_table_request(&request)?;
+
+        self.write_table_entry(table_request).await?;
+
+        Ok(())
+    }
+
+    /// Writes values into multiple registers or indices.
+    pub async fn write_register_entries(
+        &self,
+        requests: Vec<register::Request>,
+    ) -> Result<(), RBFRTError> {
+        debug!("Write register {requests:?}");
+
+        let write_req = requests
+            .iter()
+            .map(|req| self.register_to_table_request(req))
+            .collect::<Result<Vec<Request>, RBFRTError>>()?;
+
+        self.write_table_entries(write_req).await?;
+
+        Ok(())
+    }
+
+    /// Writes a value into a register, independent of whether the index was written before.
+    ///
+    /// Repeating the same request always results in the same register state.
+    /// See [upsert_table_entry](crate::SwitchConnection::upsert_table_entry).
+    pub async fn upsert_register_entry(
+        &self,
+        request: register::Request,
+    ) -> Result<(), RBFRTError> {
+        debug!("Upsert register {request:?}");
+        let table_request = self.register_to_table_request(&request)?;
+
+        self.upsert_table_entry(table_request).await?;
+
+        Ok(())
+    }
+
+    /// Writes values into multiple registers or indices, independent of whether the indices were written before.
+    ///
+    /// See [upsert_table_entries](crate::SwitchConnection::upsert_table_entries).
+    pub async fn upsert_register_entries(
+        &self,
+        requests: Vec<register::Request>,
+    ) -> Result<(), RBFRTError> {
+        debug!("Upsert register {requests:?}");
+
+        let upsert_req = requests
+            .iter()
+            .map(|req| self.register_to_table_request(req))
+            .collect::<Result<Vec<Request>, RBFRTError>>()?;
+
+        self.upsert_table_entries(upsert_req).await?;
+
+        Ok(())
+    }
+
+    /// Reads the values of a direct or indirect counter.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rbfrt::{counter, SwitchConnection};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let switch = SwitchConnection::builder("localhost", 50052)
+    ///         .device_id(0)
+    ///         .client_id(1)
+    ///         .p4_name("my_p4_program")
+    ///         .connect()
+    ///         .await?;
+    ///
+    ///     let req = counter::Request::new("ingress.port_counter").index(5).sync(true);
+    ///
+    ///     let counter = switch.get_counter_entry(req).await?;
+    ///
+    ///     if let Some(entry) = counter.get(5) {
+    ///         println!("{:?} packets, {:?} bytes", entry.packets(), entry.bytes());
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_counter_entry(
+        &self,
+        request: counter::Request,
+    ) -> Result<Counter, RBFRTError> {
+        self.get_counter_entries(vec![request]).await
+    }
+
+    /// Reads the values of multiple indices or entries of a counter.
+    ///
+    /// All requests have to refer to the same counter.
+    /// Counters of requests with [sync](crate::counter::Request::sync) are synchronized once before they are read.
+    pub async fn get_counter_entries(
+        &self,
+        requests: Vec<counter::Request>,
+    ) -> Result<Counter, RBFRTError> {
+        debug!("Read counter {requests:?}");
+
+        let name = single_name(requests.iter().map(|r| r.get_name()))?;
+
+        let mut synced: Vec<&str> = vec![];
+
+        for req in requests.iter().filter(|r| r.is_sync()) {
+            if !synced.contains(&req.get_name()) {
+                self.execute_operation(
+                    Request::new(req.get_name()).operation(TableOperation::SyncCounters),
+                )
+                .await?;
+                synced.push(req.get_name());
+            }
+        }
+
+        // requests of each pipe are read together to assign the values to their pipe
+        let mut pipes: Vec<Option<u32>> = vec![];
+
+        for req in &requests {
+            if !pipes.contains(&req.get_pipe()) {
+                pipes.push(req.get_pipe());
+            }
+        }
+
+        let mut entries = vec![];
+
+        for pipe in pipes {
+            let table_requests = requests
+                .iter()
+                .filter(|r| r.get_pipe() == pipe)
+                .map(|r| self.counter_to_table_request(r))
+                .collect();
+
+            let table_entries = self.get_tables_entries(table_requests).await?;
+
+            entries.append(&mut Counter::parse_counter_entries(table_entries, pipe));
+        }
+
+        Ok(Counter::new(&name, entries))
+    }
+
+    /// Resets the values of a counter index or direct counter entry to zero.
+    ///
+    /// The action and action data of a match-action entry with a direct counter are kept.
+    pub async fn reset_counter_entry(&self, request: counter::Request) -> Result<(), RBFRTError> {
+        self.reset_counter_entries(vec![request]).await
+    }
+
+    /// Resets the values of multiple counter indices or direct counter entries to zero.
+    pub async fn reset_counter_entries(
+        &self,
+        requests: Vec<counter::Request>,
+    ) -> Result<(), RBFRTError> {
+        debug!("Reset counter {requests:?}");
+        let bfrt_info = self.bfrt_info.as_ref().unwrap();
+
+        let mut update_req = vec![];
+
+        for req in &requests {
+            let table = bfrt_info.table_get(req.get_name())?;
+            let mut table_request = self.counter_to_table_request(req);
+
+            // a counter may count only bytes or only packets
+            for field in [COUNTER_SPEC_BYTES, COUNTER_SPEC_PKTS] {
+                if table.get_singleton_by_name(field).is_ok() {
+                    table_request = table_request.action_data(field, 0u64);
+                }
+            }
+
+            update_req.push(table_request);
+        }
+
+        let update_req = self.keep_entry_actions(update_req).await?;
+
+        self.update_table_entries(update_req).await
+    }
+
+    /// Adds the current action and action data of the entries to requests that modify direct resources of match-action tables.
+    ///
+    /// A modification without the action would replace or reject the action of the entry.
+    /// Requests to other tables, e.g., counter or meter tables, are returned unchanged.
+    async fn keep_entry_actions(&self, requests: Vec<Request>) -> Result<Vec<Request>, RBFRTError> {
+        let bfrt_info = self.bfrt_info.as_ref().unwrap();
+
+        let mut direct = vec![];
+
+        for (i, req) in requests.iter().enumerate() {
+            let table = bfrt_info.table_get(req.get_table_name())?;
+
+            if *table.get_table_type() == TableType::MatchActionDirect {
+                direct.push(i);
+            }
+        }
+
+        if direct.is_empty() {
+            return Ok(requests);
+        }
+
+        let read_req = direct
+            .iter()
+            .map(|i| {
+                let req = &requests[*i];
+                let mut read =
+                    Request::new(req.get_table_name()).match_keys(req.get_match_keys().clone());
+
+                if let Some(pipe) = req.get_pipe() {
+                    read = read.pipe(pipe);
+                }
+
+                read
+            })
+            .collect();
+
+        let entries = self.get_tables_entries(read_req).await?;
+
+        if entries.len() != direct.len() {
+            return Err(EntryNotFound {
+                message: format!(
+                    "read {} of {} entries with direct resources",
+                    entries.len(),
+                    direct.len()
+                ),
+            });
+        }
+
+        let mut requests = requests;
+
+        for (i, entry) in direct.into_iter().zip(entries) {
+            let mut req = requests[i].clone().action(entry.get_action_name());
+
+            // direct resources are written by the request itself
+            for data in entry
+                .action_data
+                .iter()
+                .filter(|d| !d.get_key().starts_with('$'))
+            {
+                req = req.action_data(data.get_key(), data.get_data().clone());
+            }
+
+            requests[i] = req;
+        }
+
+        Ok(requests)
+    }
+
+    /// Converts a counter request into a table request.
+    fn counter_to_table_request(&self, request: &counter::Request) -> Request {
+        let mut table_request = Request::new(request.get_name())
+            .from_hw(request.is_from_hw())
+            .match_keys(request.get_match_keys().clone());
+
+        if let Some(index) = request.get_index() {
+            table_request = table_request.match_key(COUNTER_INDEX, MatchValue::exact(*index));
+        }
+
+        if let Some(pipe) = request.get_pipe() {
+            table_request = table_request.pipe(pipe);
+        }
+
+        table_request
+    }
+
+    /// Reads the configuration of a direct or indirect meter.
+    pub async fn get_meter_entry(&self, request: meter::Request) -> Result<Meter, RBFRTError> {
+        self.get_meter_entries(vec![request]).await
+    }
+
+    /// Reads the configuration of multiple indices or entries of a meter.
+    pub async fn get_meter_entries(
+        &self,
+        requests: Vec<meter::Request>,
+    ) -> Result<Meter, RBFRTError> {
+        debug!("Read meter {requests:?}");
+
+        let name = single_name(requests.iter().map(|r| r.get_name()))?;
+
+        let table_requests = requests
+            .iter()
+            .map(|r| self.meter_to_table_request(r))
+            .collect();
+
+        let entries = self.get_tables_entries(table_requests).await?;
+
+        Meter::parse_meter_entries(entries, &name)
+    }
+
+    /// Writes the [MeterSpec](crate::meter::MeterSpec) of a meter index or direct meter entry.
+    ///
+    /// The action and action data of a match-action entry with a direct meter are kept.
+    /// Returns [InvalidMeterSpec](RBFRTError::InvalidMeterSpec) if the request has no spec, the spec does not match the meter type in the schema,
+    /// or a rate or burst size exceeds the width of its field.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rbfrt::{meter, SwitchConnection};
+    /// use rbfrt::meter::{MeterSpec, Rate};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let switch = SwitchConnection::builder("localhost", 50052)
+    ///         .device_id(0)
+    ///         .client_id(1)
+    ///         .p4_name("my_p4_program")
+    ///         .connect()
+    ///         .await?;
+    ///
+    ///     let spec = MeterSpec::bytes(Rate::Mbps(100), Rate::Mbps(200), 1000, 2000)?;
+    ///     let req = meter::Request::new("ingress.port_meter").index(5).spec(spec);
+    ///
+    ///     switch.write_meter_entry(req).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn write_meter_entry(&self, request: meter::Request) -> Result<(), RBFRTError> {
+        self.write_meter_entries(vec![request]).await
+    }
+
+    /// Writes the [MeterSpecs](crate::meter::MeterSpec) of multiple meter indices or direct meter entries.
+    pub async fn write_meter_entries(
+        &self,
+        requests: Vec<meter::Request>,
+    ) -> Result<(), RBFRTError> {
+        debug!("Write meter {requests:?}");
+        let bfrt_info = self.bfrt_info.as_ref().unwrap();
+
+        let mut update_req = vec![];
+
+        for req in &requests {
+            let Some(spec) = req.get_spec() else {
+                return Err(InvalidMeterSpec {
+                    message: format!("no spec for meter {}", req.get_name()),
+                });
+            };
+
+            let table = bfrt_info.table_get(req.get_name())?;
+            let mut table_request = self.meter_to_table_request(req);
+
+            for (field, value) in spec.fields() {
+                if table.get_singleton_by_name(field).is_err() {
+                    return Err(InvalidMeterSpec {
+                        message: format!(
+                            "meter {} has no field {field}, it is not {}-based",
+                            table.name(),
+                            if spec.is_packet_based() {
+                                "packet"
+                            } else {
+                                "byte"
+                            }
+                        ),
+                    });
+                }
+
+                check_int_field(table, field, value)
+                    .map_err(|message| InvalidMeterSpec { message })?;
+
+                table_request = table_request.action_data(field, value);
+            }
+
+            update_req.push(table_request);
+        }
+
+        let update_req = self.keep_entry_actions(update_req).await?;
+
+        self.update_table_entries(update_req).await
+    }
+
+    /// Converts a meter request into a table request.
+    fn meter_to_table_request(&self, request: &meter::Request) -> Request {
+        let mut table_request = Request::new(request.get_name())
+            .from_hw(request.is_from_hw())
+            .match_keys(request.get_match_keys().clone());
+
+        if let Some(index) = request.get_index() {
+            table_request = table_request.match_key(METER_INDEX, MatchValue::exact(*index));
+        }
+
+        if let Some(pipe) = request.get_pipe() {
+            table_request = table_request.pipe(pipe);
+        }
+
+        table_request
+    }
+
+    /// Reads the configuration of a direct or indirect low-pass filter.
+    pub async fn get_lpf_entry(&self, request: lpf::Request) -> Result<Lpf, RBFRTError> {
+        self.get_lpf_entries(vec![request]).await
+    }
+
+    /// Reads the configuration of multiple indices or entries of a low-pass filter.
+    pub async fn get_lpf_entries(&self, requests: Vec<lpf::Request>) -> Result<Lpf, RBFRTError> {
+        debug!("Read LPF {requests:?}");
+
+        if requests.is_empty() {
+            return Err(RequestEmpty {});
+        }
+
+        let name = requests.first().unwrap().get_name().to_owned();
+
+        let entries = self
+            .get_tables_entries(
+                requests
+                    .iter()
+                    .map(|r| self.lpf_to_table_request(r))
+                    .collect::<Result<Vec<Request>, RBFRTError>>()?,
+            )
+            .await?;
+
+        Lpf::parse_lpf_entries(entries, &name)
+    }
+
+    /// Writes the [LpfSpec](crate::lpf::LpfSpec) of a low-pass filter index or direct filter entry.
+    pub async fn write_lpf_entry(&self, request: lpf::Request) -> Result<(), RBFRTError> {
+        self.write_lpf_entries(vec![request]).await
+    }
+
+    /// Writes the [LpfSpecs](crate::lpf::LpfSpec) of multiple low-pass filter indices or direct filter entries.
+    pub async fn write_lpf_entries(&self, requests: Vec<lpf::Request>) -> Result<(), RBFRTError> {
+        debug!("Write LPF {requests:?}");
+        self.update_table_entries(
+            requests
+                .iter()
+                .map(|r| self.lpf_to_table_request(r))
+                .collect::<Result<Vec<Request>, RBFRTError>>()?,
+        )
+        .await
+    }
+
+    /// Converts a low-pass filter request into a table request.
+    ///
+    /// The spec of the request is validated against the schema of the filter.
+    fn lpf_to_table_request(&self, request: &lpf::Request) -> Result<Request, RBFRTError> {
+        let mut table_request = Request::new(request.get_name())
+            .from_hw(request.is_from_hw())
+            .match_keys(request.get_match_keys().clone());
+
+        if let Some(index) = request.get_index() {
+            table_request = table_request.match_key(LPF_INDEX, MatchValue::exact(*index));
+        }
+
+        if let Some(pipe) = request.get_pipe() {
+            table_request = table_request.pipe(pipe);
+        }
+
+        if let Some(spec) = request.get_spec() {
+            let table = self
+                .bfrt_info
+                .as_ref()
+                .unwrap()
+                .table_get(request.get_name())?;
+
+            let check = || {
+                for (field, value) in [
+                    (LPF_SPEC_GAIN_TIME_CONSTANT_NS, spec.gain_time_constant_ns),
+                    (LPF_SPEC_DECAY_TIME_CONSTANT_NS, spec.decay_time_constant_ns),
+                ] {
+                    check_field_type(table, field, &["float"])?;
+
+                    if !value.is_finite() || value < 0f32 {
+                        return Err(format!(
+                            "{field} must be a non-negative number, got {value}"
+                        ));
+                    }
+                }
+
+                check_field_type(table, LPF_SPEC_TYPE, &["string"])?;
+                check_int_field(
+                    table,
+                    LPF_SPEC_OUT_SCALE_DOWN_FACTOR,
+                    spec.output_scale_down_factor as u64,
+                )
+            };
+
+            check().map_err(|message| InvalidLpfSpec { message })?;
+
+            table_request = table_request
+                .action_data(LPF_SPEC_TYPE, spec.lpf_type.as_str())
+                .action_data(LPF_SPEC_GAIN_TIME_CONSTANT_NS, spec.gain_time_constant_ns)
+                .action_data(LPF_SPEC_DECAY_TIME_CONSTANT_NS, spec.decay_time_constant_ns)
+                .action_data(
+                    LPF_SPEC_OUT_SCALE_DOWN_FACTOR,
+                    spec.output_scale_down_factor,
+                );
+        }
+
+        Ok(table_request)
+    }
+
+    /// Reads the configuration of a direct or indirect WRED.
+    pub async fn get_wred_entry(&self, request: wred::Request) -> Result<Wred, RBFRTError> {
+        self.get_wred_entries(vec![request]).await
+    }
+
+    /// Reads the configuration of multiple indices or entries of a WRED.
+    pub async fn get_wred_entries(&self, requests: Vec<wred::Request>) -> Result<Wred, RBFRTError> {
+        debug!("Read WRED {requests:?}");
+
+        if requests.is_empty() {
+            return Err(RequestEmpty {});
+        }
+
+        let name = requests.first().unwrap().get_name().to_owned();
+
+        let entries = self
+            .get_tables_entries(
+                requests
+                    .iter()
+                    .map(|r| self.wred_to_table_request(r))
+                    .collect::<Result<Vec<Request>, RBFRTError>>()?,
+            )
+            .await?;
+
+        Wred::parse_wred_entries(entries, &name)
+    }
+
+    /// Writes the [WredSpec](crate::wred::WredSpec) of a WRED index or direct WRED entry.
+    pub async fn write_wred_entry(&self, request: wred::Request) -> Result<(), RBFRTError> {
+        self.write_wred_entries(vec![request]).await
+    }
+
+    /// Writes the [WredSpecs](crate::wred::WredSpec) of multiple WRED indices or direct WRED entries.
+    pub async fn write_wred_entries(&self, requests: Vec<wred::Request>) -> Result<(), RBFRTError> {
+        debug!("Write WRED {requests:?}");
+        self.update_table_entries(
+            requests
+                .iter()
+                .map(|r| self.wred_to_table_request(r))
+                .collect::<Result<Vec<Request>, RBFRTError>>()?,
+        )
+        .await
+    }
+
+    /// Converts a WRED request into a table request.
+    ///
+    /// The spec of the request is validated against the schema of the WRED.
+    fn wred_to_table_request(&self, request: &wred::Request) -> Result<Request, RBFRTError> {
+        let mut table_request = Request::new(request.get_name())
+            .from_hw(request.is_from_hw())
+            .match_keys(request.get_match_keys().clone());
+
+        if let Some(index) = request.get_index() {
+            table_request = table_request.match_key(WRED_INDEX, MatchValue::exact(*index));
+        }
+
+        if let Some(pipe) = request.get_pipe() {
+            table_request = table_request.pipe(pipe);
+        }
+
+        if let Some(spec) = request.get_spec() {
+            let table = self
+                .bfrt_info
+                .as_ref()
+                .unwrap()
+                .table_get(request.get_name())?;
+
+            let check = || {
+                check_field_type(table, WRED_SPEC_TIME_CONSTANT_NS, &["float"])?;
+                check_field_type(table, WRED_SPEC_MAX_PROBABILITY, &["float"])?;
+                check_int_field(
+                    table,
+                    WRED_SPEC_MIN_THRESH_CELLS,
+                    spec.min_threshold_cells as u64,
+                )?;
+                check_int_field(
+                    table,
+                    WRED_SPEC_MAX_THRESH_CELLS,
+                    spec.max_threshold_cells as u64,
+                )?;
+
+                if !spec.time_constant_ns.is_finite() || spec.time_constant_ns < 0f32 {
+                    return Err(format!(
+                        "{WRED_SPEC_TIME_CONSTANT_NS} must be a non-negative number, got {}",
+                        spec.time_constant_ns
+                    ));
+                }
+
+                if !(0f32..=1f32).contains(&spec.max_probability) {
+                    return Err(format!(
+                        "{WRED_SPEC_MAX_PROBABILITY} must be between 0 and 1, got {}",
+                        spec.max_probability
+                    ));
+                }
+
+                if spec.min_threshold_cells > spec.max_threshold_cells {
+                    return Err(format!(
+                        "minimum threshold {} exceeds maximum threshold {}",
+                        spec.min_threshold_cells, spec.max_threshold_cells
+                    ));
+                }
+
+                Ok(())
+            };
+
+            check().map_err(|message| InvalidWredSpec { message })?;
+
+            table_request = table_request
+                .action_data(WRED_SPEC_TIME_CONSTANT_NS, spec.time_constant_ns)
+                .action_data(WRED_SPEC_MIN_THRESH_CELLS, spec.min_threshold_cells)
+                .action_data(WRED_SPEC_MAX_THRESH_CELLS, spec.max_threshold_cells)
+                .action_data(WRED_SPEC_MAX_PROBABILITY, spec.max_probability);
+        }
+
+        Ok(table_request)
+    }
+
+    /// Adds members to the action profile with the given `name`.
+    ///
+    /// Members are referenced by the groups of an action selector or directly by the entries of an indirect match-action table.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use rbfrt::selector::{ActionMember, GroupMember, SelectorGroup};
+    /// use rbfrt::{SwitchConnection, table};
+    /// use rbfrt::table::MatchValue;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let switch = SwitchConnection::builder("localhost", 50052)
+    ///         .device_id(0)
+    ///         .client_id(1)
+    ///         .p4_name("my_p4_program")
+    ///         .connect()
+    ///         .await?;
+    ///
+    ///     switch
+    ///         .add_action_members(
+    ///             "ingress.ecmp_profile",
+    ///             vec![
+    ///                 ActionMember::new(1, "ingress.set_port").action_data("port", 128),
+    ///                 ActionMember::new(2, "ingress.set_port").action_data("port", 136),
+    ///             ],
+    ///         )
+    ///         .await?;
+    ///
+    ///     let group = SelectorGroup::new(10, 8)
+    ///         .member(GroupMember::new(1))
+    ///         .member(GroupMember::new(2));
+    ///
+    ///     switch.add_selector_group("ingress.ecmp_selector", group).await?;
+    ///
+    ///     let req = table::Request::new("ingress.ecmp")
+    ///         .match_key("hdr.ipv4.dst_addr", MatchValue::exact(0x0a000001))
+    ///         .selector_group(10);
+    ///
+    ///     switch.write_table_entry(req).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn add_action_members(
+        &self,
+        name: &str,
+        members: Vec<ActionMember>,
+    ) -> Result<(), RBFRTError> {
+        debug!("Add action members to {name}: {members:?}");
+        let req = members
             .iter()
-            .map(|x| x.clone().request_type(RequestType::Delete))
+            .map(|m| {
+                let mut req = Request::new(name)
+                    .match_key(ACTION_MEMBER_ID, MatchValue::exact(m.get_id()))
+                    .action(m.get_action_name());
+
+                for data in m.get_action_data() {
+                    req = req.action_data(data.get_key(), data.get_data().clone());
+                }
+
+                req
+            })
             .collect();
 
-        self.dispatch_request(&vec_req).await?;
+        self.write_table_entries(req).await
+    }
 
-        Ok(())
+    /// Reads all members of the action profile with the given `name`.
+    pub async fn get_action_members(&self, name: &str) -> Result<Vec<ActionMember>, RBFRTError> {
+        debug!("Read action members of {name}");
+        let entries = self.get_table_entries(Request::new(name)).await?;
+
+        entries.into_iter().map(ActionMember::parse).collect()
     }
 
-    /// Deletes all entries in a table.
+    /// Deletes members from the action profile with the given `name`.
     ///
-    /// See [delete_table_entry](crate::SwitchConnection::delete_table_entry) or [delete_table_entries](crate::SwitchConnection::delete_table_entries) to delete one or multiple entries inside the table.
-    pub async fn clear_table(&self, name: &str) -> Result<(), RBFRTError> {
-        debug!("Clear table : {name}");
-        let req = Request::new(name);
+    /// The members are removed from all groups of the selectors that use the action profile before they are deleted.
+    /// Returns [ObjectInUse](RBFRTError::ObjectInUse) without deleting anything if an entry of a match-action table still references a member directly.
+    pub async fn delete_action_members(
+        &self,
+        name: &str,
+        member_ids: Vec<u32>,
+    ) -> Result<(), RBFRTError> {
+        debug!("Delete action members {member_ids:?} of {name}");
 
-        self.delete_table_entry(req).await?;
+        if let Some((table, id)) = self
+            .referencing_entries(name, ACTION_MEMBER_ID)
+            .await?
+            .into_iter()
+            .find(|(_, id)| member_ids.contains(id))
+        {
+            return Err(ObjectInUse {
+                name: format!("Member {id} of {name}"),
+                referenced_by: table,
+            });
+        }
 
-        Ok(())
+        // groups must not reference members that are deleted
+        for selector in self.dependent_tables(name, &[TableType::Selector])? {
+            let mut update_req = vec![];
+
+            for mut group in self.get_selector_groups(&selector).await? {
+                if group.remove_members(&member_ids) {
+                    update_req.push(self.selector_group_to_table_request(&selector, &group)?);
+                }
+            }
+
+            if !update_req.is_empty() {
+                self.update_table_entries(update_req).await?;
+            }
+        }
+
+        let req = member_ids
+            .iter()
+            .map(|id| Request::new(name).match_key(ACTION_MEMBER_ID, MatchValue::exact(*id)))
+            .collect();
+
+        self.delete_table_entries(req).await
     }
 
-    /// Deletes all entries in multiple tables.
+    /// Adds a [SelectorGroup] to the action selector with the given `name`.
     ///
-    /// See [delete_table_entry](crate::SwitchConnection::delete_table_entry) or [delete_table_entries](crate::SwitchConnection::delete_table_entries) to delete one or multiple entries inside the tables.
-    pub async fn clear_tables(&self, name: Vec<&str>) -> Result<(), RBFRTError> {
-        debug!("Clear tables : {name:?}");
-        let reqs: Vec<Request> = name.iter().map(|x| Request::new(x)).collect();
+    /// The members of the group have to be present in the action profile of the selector.
+    /// Returns [AttributeNotSupported](RBFRTError::AttributeNotSupported) if a member has a weight other than `1` but the selector does not support weights.
+    pub async fn add_selector_group(
+        &self,
+        name: &str,
+        group: SelectorGroup,
+    ) -> Result<(), RBFRTError> {
+        debug!("Add selector group to {name}: {group:?}");
+        let req = self.selector_group_to_table_request(name, &group)?;
 
-        self.delete_table_entries(reqs).await?;
+        self.write_table_entry(req).await
+    }
 
-        Ok(())
+    /// Replaces the members of an existing [SelectorGroup] of the action selector with the given `name`.
+    pub async fn update_selector_group(
+        &self,
+        name: &str,
+        group: SelectorGroup,
+    ) -> Result<(), RBFRTError> {
+        debug!("Update selector group of {name}: {group:?}");
+        let req = self.selector_group_to_table_request(name, &group)?;
+
+        self.update_table_entry(req).await
     }
 
-    /// Reads the value of a register.
-    pub async fn get_register_entry(
+    /// Enables or disables a member of a group of the action selector with the given `name`.
+    ///
+    /// Disabled members stay in the group but are no longer selected for traffic.
+    pub async fn set_group_member_status(
         &self,
-        request: register::Request,
-    ) -> Result<Register, RBFRTError> {
-        debug!("Read register {request:?}");
-        let mut table_request = Request::new(request.get_name()).request_type(RequestType::Read);
+        name: &str,
+        group_id: u32,
+        member_id: u32,
+        enabled: bool,
+    ) -> Result<(), RBFRTError> {
+        debug!("Set status of member {member_id} in group {group_id} of {name}: {enabled}");
+        let mut group = self.get_selector_group(name, group_id).await?;
+
+        match group.get_member_mut(member_id) {
+            Some(member) => member.enabled = enabled,
+            None => {
+                return Err(UnknownGroupMember {
+                    table_name: name.to_owned(),
+                    group_id,
+                    member_id,
+                });
+            }
+        }
 
-        if request.get_index().is_some() {
-            table_request = table_request.match_key(
-                "$REGISTER_INDEX",
-                MatchValue::exact(request.get_index().unwrap()),
-            );
+        self.update_selector_group(name, group).await
+    }
+
+    /// Reads the group with the given `group_id` of the action selector with the given `name`.
+    pub async fn get_selector_group(
+        &self,
+        name: &str,
+        group_id: u32,
+    ) -> Result<SelectorGroup, RBFRTError> {
+        debug!("Read selector group {group_id} of {name}");
+        self.check_selector(name)?;
+        let req = Request::new(name).match_key(SELECTOR_GROUP_ID, MatchValue::exact(group_id));
+
+        let mut groups = self
+            .get_table_entries(req)
+            .await?
+            .into_iter()
+            .map(SelectorGroup::parse)
+            .collect::<Result<Vec<SelectorGroup>, RBFRTError>>()?;
+
+        match groups.pop() {
+            Some(group) => Ok(group),
+            None => Err(UnknownReadResult {}),
         }
+    }
 
-        let entries = self.get_table_entries(table_request).await?;
+    /// Reads all groups of the action selector with the given `name`.
+    pub async fn get_selector_groups(&self, name: &str) -> Result<Vec<SelectorGroup>, RBFRTError> {
+        debug!("Read selector groups of {name}");
+        self.check_selector(name)?;
+        let entries = self.get_table_entries(Request::new(name)).await?;
 
-        let name = request.get_name();
+        entries.into_iter().map(SelectorGroup::parse).collect()
+    }
 
-        Ok(Register::parse_register_entries(entries, name))
+    /// Deletes the group with the given `group_id` from the action selector with the given `name`.
+    ///
+    /// Entries of match-action tables that reference the group have to be deleted first.
+    pub async fn delete_selector_group(&self, name: &str, group_id: u32) -> Result<(), RBFRTError> {
+        debug!("Delete selector group {group_id} of {name}");
+        self.check_selector(name)?;
+        let req = Request::new(name).match_key(SELECTOR_GROUP_ID, MatchValue::exact(group_id));
+
+        self.delete_table_entry(req).await
     }
 
-    /// Reads the values of multiple registers or indices.
-    pub async fn get_register_entries(
-        &self,
-        requests: Vec<register::Request>,
-    ) -> Result<Register, RBFRTError> {
-        debug!("Read register {requests:?}");
+    /// Deletes all groups of the action selector with the given `name` and afterwards the members of its action profiles.
+    ///
+    /// Members that are still referenced by groups of other selectors or by entries of match-action tables are kept.
+    /// Returns [ObjectInUse](RBFRTError::ObjectInUse) without deleting anything if an entry of a match-action table still references a group.
+    pub async fn clear_selector(&self, name: &str) -> Result<(), RBFRTError> {
+        debug!("Clear selector {name}");
+        let bfrt_info = self.bfrt_info.as_ref().unwrap();
+        let selector = self.check_selector(name)?;
 
-        let name = requests.first().as_ref().unwrap().get_name();
+        if let Some((table, id)) = self
+            .referencing_entries(name, SELECTOR_GROUP_ID)
+            .await?
+            .into_iter()
+            .next()
+        {
+            return Err(ObjectInUse {
+                name: format!("Group {id} of {name}"),
+                referenced_by: table,
+            });
+        }
 
-        let mut req = vec![];
+        let profiles: Vec<String> = selector
+            .depends_on()
+            .iter()
+            .filter_map(|id| bfrt_info.table_get_by_id(*id).ok())
+            .filter(|t| *t.get_table_type() == TableType::ActionProfile)
+            .map(|t| t.name().to_owned())
+            .collect();
 
-        for request in &requests {
-            let table_request = Request::new(request.get_name()).request_type(RequestType::Read);
+        self.clear_table(name).await?;
 
-            if request.get_index().is_some() {
-                req.push(table_request.match_key(
-                    "$REGISTER_INDEX",
-                    MatchValue::exact(request.get_index().unwrap()),
-                ));
+        for profile in &profiles {
+            let mut in_use: Vec<u32> = self
+                .referencing_entries(profile, ACTION_MEMBER_ID)
+                .await?
+                .into_iter()
+                .map(|(_, id)| id)
+                .collect();
+
+            for other in self.dependent_tables(profile, &[TableType::Selector])? {
+                for group in self.get_selector_groups(&other).await? {
+                    in_use.extend(group.get_members().iter().map(|m| m.member_id));
+                }
             }
-        }
 
-        let entries = self.get_tables_entries(req).await?;
+            let req: Vec<Request> = self
+                .get_action_members(profile)
+                .await?
+                .iter()
+                .filter(|m| !in_use.contains(&m.get_id()))
+                .map(|m| {
+                    Request::new(profile).match_key(ACTION_MEMBER_ID, MatchValue::exact(m.get_id()))
+                })
+                .collect();
 
-        Ok(Register::parse_register_entries(entries, name))
+            if !req.is_empty() {
+                self.delete_table_entries(req).await?;
+            }
+        }
+
+        Ok(())
     }
 
-    /// Writes a value into a register.
-    pub async fn write_register_entry(&self, request: register::Request) -> Result<(), RBFRTError> {
-        debug!("Write register {request:?}");
-        let mut table_request = Request::new(request.get_name());
+    /// Returns the names of the tables with one of the given `types` that depend on the table with the given `name`.
+    fn dependent_tables(&self, name: &str, types: &[TableType]) -> Result<Vec<String>, RBFRTError> {
+        let bfrt_info = self.bfrt_info.as_ref().unwrap();
+        let id = bfrt_info.table_get(name)?.id();
 
-        if request.get_index().is_none() {
-            return Err(RBFRTError::MissingRegisterIndex);
+        Ok(bfrt_info
+            .get_tables()
+            .iter()
+            .filter(|t| types.contains(t.get_table_type()) && t.depends_on().contains(&id))
+            .map(|t| t.name().to_owned())
+            .collect())
+    }
+
+    /// Returns the ids referenced by the data field `field` of the entries of the match-action tables that depend on the table with the given `name`.
+    ///
+    /// Each id is returned together with the name of the referencing table.
+    async fn referencing_entries(
+        &self,
+        name: &str,
+        field: &str,
+    ) -> Result<Vec<(String, u32)>, RBFRTError> {
+        let tables = self.dependent_tables(
+            name,
+            &[
+                TableType::MatchActionIndirect,
+                TableType::MatchActionIndirectSelector,
+            ],
+        )?;
+
+        let mut references = vec![];
+
+        for table in tables {
+            for entry in self.get_table_entries(Request::new(&table)).await? {
+                if let Ok(data) = entry.get_action_data(field) {
+                    references.push((table.clone(), data.as_u32()));
+                }
+            }
         }
 
-        table_request = table_request.match_key(
-            "$REGISTER_INDEX",
-            MatchValue::exact(request.get_index().unwrap()),
-        );
+        Ok(references)
+    }
 
-        for (name, value) in request.get_data() {
-            table_request = table_request.action_data(name, value.clone());
-        }
+    /// Returns the table of the action selector with the given `name`.
+    fn check_selector(&self, name: &str) -> Result<&BFRTTableObject, RBFRTError> {
+        let table = self.bfrt_info.as_ref().unwrap().table_get(name)?;
 
-        self.write_table_entry(table_request).await?;
+        if *table.get_table_type() != TableType::Selector {
+            return Err(NoSelector {
+                table_name: name.to_owned(),
+            });
+        }
 
-        Ok(())
+        Ok(table)
     }
 
-    /// Writes values into multiple registers or indices.
-    pub async fn write_register_entries(
+    /// Converts a selector group into a table request.
+    fn selector_group_to_table_request(
         &self,
-        requests: Vec<register::Request>,
-    ) -> Result<(), RBFRTError> {
-        debug!("Write register {requests:?}");
-
-        let mut write_req = vec![];
+        name: &str,
+        group: &SelectorGroup,
+    ) -> Result<Request, RBFRTError> {
+        let table = self.check_selector(name)?;
+        let supports_weights = table.get_singleton_by_name(ACTION_MEMBER_WEIGHT).is_ok();
+
+        if group.is_weighted() && !supports_weights {
+            return Err(AttributeNotSupported {
+                table_name: name.to_owned(),
+                attribute: ACTION_MEMBER_WEIGHT.to_owned(),
+            });
+        }
 
-        for req in &requests {
-            if req.get_index().is_none() {
-                return Err(RBFRTError::MissingRegisterIndex);
-            }
+        let members = group.get_members();
+
+        let mut req = Request::new(name)
+            .match_key(SELECTOR_GROUP_ID, MatchValue::exact(group.get_id()))
+            .action_data(MAX_GROUP_SIZE, group.get_max_size())
+            .action_data_repeated(
+                ACTION_MEMBER_ID,
+                members.iter().map(|m| m.member_id).collect(),
+            )
+            .action_data_repeated(
+                ACTION_MEMBER_STATUS,
+                members.iter().map(|m| m.enabled).collect(),
+            );
 
-            let mut table_request = Request::new(req.get_name()).match_key(
-                "$REGISTER_INDEX",
-                MatchValue::exact(req.get_index().unwrap()),
+        if supports_weights {
+            req = req.action_data_repeated(
+                ACTION_MEMBER_WEIGHT,
+                members.iter().map(|m| m.weight).collect(),
             );
+        }
 
-            for (name, value) in req.get_data() {
-                table_request = table_request.action_data(name, value.clone());
-            }
+        Ok(req)
+    }
 
-            write_req.push(table_request);
+    /// Converts a [register::Request] into a table [Request] that writes the register's index.
+    fn register_to_table_request(
+        &self,
+        request: &register::Request,
+    ) -> Result<Request, RBFRTError> {
+        if request.get_index().is_none() {
+            return Err(RBFRTError::MissingRegisterIndex);
         }
 
-        self.write_table_entries(write_req).await?;
+        let mut table_request = Request::new(request.get_name()).match_key(
+            "$REGISTER_INDEX",
+            MatchValue::exact(request.get_index().unwrap()),
+        );
 
-        Ok(())
+        for (name, value) in request.get_data() {
+            table_request = table_request.action_data(name, value.clone());
+        }
+
+        Ok(register_target(request, table_request))
     }
 
     /// Dispatches the requests to the switch.
     ///
     /// # Note
     ///
-    /// Assumes all request are of the same kind.
-    /// You MUST NOT mix different request types, e.g., [Read](crate::table::table_entry::Read) and [Write](crate::table::table_entry::Write), in one dispatch!
+    /// Write requests of different types, e.g., [Write](crate::table::RequestType::Write) and [Delete](crate::table::RequestType::Delete), may be mixed in one dispatch.
+    /// You MUST NOT mix [Read](crate::table::RequestType::Read) requests with any other request type in one dispatch!
     async fn dispatch_request(&self, request: &Vec<Request>) -> Result<DispatchResult, RBFRTError> {
+        self.dispatch_atomic_request(request, Atomicity::ContinueOnError, true)
+            .await
+    }
+
+    /// Dispatches the requests to the switch with the given [Atomicity].
+    ///
+    /// The [Atomicity] only applies to write requests, see [dispatch_request](crate::SwitchConnection::dispatch_request).
+    /// Write requests are only split into chunks if `split` is set, see [dispatch_updates](crate::SwitchConnection::dispatch_updates).
+    async fn dispatch_atomic_request(
+        &self,
+        request: &Vec<Request>,
+        atomicity: Atomicity,
+        split: bool,
+    ) -> Result<DispatchResult, RBFRTError> {
         let bfrt_info = self.bfrt_info.as_ref().unwrap();
 
         if request.is_empty() {
             return Err(RequestEmpty {});
         }
 
+        self.check_entry_scopes(request).await?;
+
         match request.first().as_ref().unwrap().get_type() {
             RequestType::Read => {
                 let mut entities = vec![];
@@ -959,69 +3413,293 @@ impl SwitchConnection {
 
                 Ok(DispatchResult::ReadResult { response })
             }
-            RequestType::Write | RequestType::Update => {
+            _ => {
                 let mut updates = vec![];
 
                 for req in request {
                     let table = bfrt_info.table_get(req.get_table_name())?;
-                    let update = table.build_write_request(req, &self.target)?;
+                    let update = table.build_update(req, &self.target)?;
                     updates.push(update);
                 }
 
-                let req = WriteRequest {
-                    target: Some(self.get_target_device()),
-                    client_id: self.client_id,
-                    updates,
-                    p4_name: self.p4_name.as_ref().unwrap().to_owned(),
-                    atomicity: 0,
-                };
+                let responses = self
+                    .dispatch_updates(request, updates, atomicity, split)
+                    .await?;
 
-                let response = self.bf_client.lock().await.write(req).await?;
+                Ok(DispatchResult::WriteResult { responses })
+            }
+        }
+    }
 
-                Ok(DispatchResult::WriteResult { response })
+    /// Sends the `updates` built from the `requests` to the switch.
+    ///
+    /// Batches that exceed the configured [batch_message_size](crate::SwitchConnectionBuilder::batch_message_size) are split into chunks if `split` is set and the [Atomicity] is [ContinueOnError](Atomicity::ContinueOnError).
+    /// Chunks of requests with the same [RequestType] are sent concurrently, chunks of mixed requests are sent in order.
+    /// Errors of all chunks are aggregated in a single [BatchError](RBFRTError::BatchError) that refers to the original request indices.
+    /// Unsplit updates that exceed the maximum gRPC message size are rejected with [TransactionTooLarge](RBFRTError::TransactionTooLarge).
+    async fn dispatch_updates(
+        &self,
+        requests: &[Request],
+        updates: Vec<bfrt_proto::Update>,
+        atomicity: Atomicity,
+        split: bool,
+    ) -> Result<Vec<WriteResponse>, RBFRTError> {
+        let chunks = if split && atomicity == Atomicity::ContinueOnError {
+            self.split_updates(updates)
+        } else {
+            let req = self.build_write_request(updates, atomicity);
+            let size = req.encoded_len();
+
+            if size > MAX_MESSAGE_SIZE {
+                return Err(TransactionTooLarge {
+                    size,
+                    limit: MAX_MESSAGE_SIZE,
+                });
             }
-            RequestType::Operation => {
-                let mut updates = vec![];
 
-                for req in request {
-                    let table = bfrt_info.table_get(req.get_table_name())?;
-                    let update = table.build_operation_request(req)?;
-                    updates.push(update);
+            vec![req.updates]
+        };
+
+        // hold the lock until all chunks are sent to serialize writes with other callers
+        let mut client = self.bf_client.lock().await;
+
+        // a single request keeps its error unchanged
+        if chunks.len() == 1 {
+            let req = self.build_write_request(chunks.into_iter().next().unwrap(), atomicity);
+            let response = client
+                .write(req)
+                .await
+                .map_err(|e| RBFRTError::from_write_status(e, atomicity, requests))?;
+
+            return Ok(vec![response.into_inner()]);
+        }
+
+        debug!(
+            "Split batch of {} updates into {} chunks.",
+            requests.len(),
+            chunks.len()
+        );
+
+        let first_type = requests.first().map(|r| r.get_type());
+        let concurrency = if requests.iter().all(|r| Some(r.get_type()) == first_type) {
+            self.batch_concurrency
+        } else {
+            1
+        };
+
+        let mut results = vec![];
+        let mut task_error = None;
+        let mut offset = 0;
+
+        if concurrency == 1 {
+            for chunk in chunks {
+                let len = chunk.len();
+                let req = self.build_write_request(chunk, atomicity);
+
+                results.push((offset, len, client.write(req).await));
+                offset += len;
+            }
+        } else {
+            let mut in_flight = JoinSet::new();
+
+            for chunk in chunks {
+                if in_flight.len() >= concurrency {
+                    Self::join_chunk(&mut in_flight, &mut results, &mut task_error).await;
                 }
 
-                let req = WriteRequest {
-                    target: Some(self.get_target_device()),
-                    client_id: self.client_id,
-                    updates,
-                    p4_name: self.p4_name.as_ref().unwrap().to_owned(),
-                    atomicity: 0,
-                };
+                let len = chunk.len();
+                let req = self.build_write_request(chunk, atomicity);
+                let mut client = client.clone();
 
-                let response = self.bf_client.lock().await.write(req).await?;
+                in_flight.spawn(async move { (offset, len, client.write(req).await) });
+                offset += len;
+            }
 
-                Ok(DispatchResult::WriteResult { response })
+            // every chunk has to finish before the result of the batch is known
+            while !in_flight.is_empty() {
+                Self::join_chunk(&mut in_flight, &mut results, &mut task_error).await;
             }
-            RequestType::Delete => {
-                let mut updates = vec![];
+        }
 
-                for req in request {
-                    let table = bfrt_info.table_get(req.get_table_name())?;
-                    let update = table.build_delete_request(req, &self.target)?;
-                    updates.push(update);
+        drop(client);
+
+        if let Some(e) = task_error {
+            return Err(e);
+        }
+
+        results.sort_by_key(|(offset, _, _)| *offset);
+
+        let mut responses = vec![];
+        let mut failures = vec![];
+        let mut message = None;
+
+        for (offset, len, result) in results {
+            match result {
+                Ok(response) => responses.push(response.into_inner()),
+                Err(status) => {
+                    failures.append(&mut RBFRTError::update_failures(
+                        &status,
+                        &requests[offset..offset + len],
+                        offset,
+                    ));
+                    // keep the message of the first failing chunk
+                    message.get_or_insert_with(|| status.message().to_owned());
                 }
+            }
+        }
 
-                let req = WriteRequest {
-                    target: Some(self.get_target_device()),
-                    client_id: self.client_id,
-                    updates,
-                    p4_name: self.p4_name.as_ref().unwrap().to_owned(),
-                    atomicity: 0,
-                };
+        if failures.is_empty() {
+            Ok(responses)
+        } else {
+            failures.sort_by_key(|f| f.index);
+            Err(RBFRTError::BatchError {
+                message: message.unwrap_or_default(),
+                failures,
+            })
+        }
+    }
+
+    /// Waits for the next chunk of a split batch to finish and stores its result.
+    ///
+    /// A failed task is recorded in `task_error` so that the remaining chunks are still awaited.
+    async fn join_chunk(
+        in_flight: &mut JoinSet<ChunkResult>,
+        results: &mut Vec<ChunkResult>,
+        task_error: &mut Option<RBFRTError>,
+    ) {
+        match in_flight.join_next().await {
+            Some(Ok(result)) => results.push(result),
+            Some(Err(e)) => {
+                task_error.get_or_insert(RBFRTError::GenericError {
+                    message: format!("Write request task failed: {e}"),
+                });
+            }
+            None => {}
+        }
+    }
 
-                let response = self.bf_client.lock().await.write(req).await?;
+    /// Splits the `updates` into chunks whose encoded size does not exceed the configured [batch_message_size](crate::SwitchConnectionBuilder::batch_message_size).
+    ///
+    /// An update that exceeds the size on its own is put into a separate chunk.
+    fn split_updates(&self, updates: Vec<bfrt_proto::Update>) -> Vec<Vec<bfrt_proto::Update>> {
+        // reserved for the remaining fields of the write request
+        let mut budget = self.batch_message_size.saturating_sub(1024);
+        let mut chunks = vec![];
+        let mut chunk = vec![];
+        let mut size = 0;
+
+        if budget == 0 {
+            budget = 1;
+        }
 
-                Ok(DispatchResult::WriteResult { response })
+        for update in updates {
+            // tag and length prefix of the repeated field
+            let len = update.encoded_len() + 6;
+
+            if !chunk.is_empty() && size + len > budget {
+                chunks.push(chunk);
+                chunk = vec![];
+                size = 0;
             }
+
+            size += len;
+            chunk.push(update);
+        }
+
+        if !chunk.is_empty() {
+            chunks.push(chunk);
+        }
+
+        chunks
+    }
+
+    fn build_write_request(
+        &self,
+        updates: Vec<bfrt_proto::Update>,
+        atomicity: Atomicity,
+    ) -> WriteRequest {
+        WriteRequest {
+            target: Some(self.get_target_device()),
+            client_id: self.client_id,
+            updates,
+            p4_name: self.p4_name.as_ref().unwrap().to_owned(),
+            atomicity: atomicity.to_proto(),
         }
     }
 }
+
+/// Applies the direction and parser of the register `request` to the `table_request`.
+fn register_target(request: &register::Request, mut table_request: Request) -> Request {
+    if let Some(direction) = request.get_direction() {
+        table_request = table_request.direction(direction);
+    }
+
+    if let Some(parser) = request.get_parser() {
+        table_request = table_request.parser(parser);
+    }
+
+    table_request
+}
+
+/// Checks that the `table` has the data field `field` with one of the given `types`.
+///
+/// Returns a message describing the mismatch otherwise.
+fn check_field_type<'a>(
+    table: &'a BFRTTableObject,
+    field: &str,
+    types: &[&str],
+) -> Result<&'a BFRTFieldType, String> {
+    let Some(field_type) = table
+        .get_singleton_by_name(field)
+        .ok()
+        .and_then(|s| s.get_type().as_ref())
+    else {
+        return Err(format!("table {} has no field {field}", table.name()));
+    };
+
+    if !types.contains(&field_type.r#type.as_str()) {
+        return Err(format!(
+            "field {field} of table {} has type {}, expected {}",
+            table.name(),
+            field_type.r#type,
+            types.join(" or ")
+        ));
+    }
+
+    Ok(field_type)
+}
+
+/// Checks that the `table` has the integer data field `field` and that `value` fits into its width.
+fn check_int_field(table: &BFRTTableObject, field: &str, value: u64) -> Result<(), String> {
+    let field_type = check_field_type(
+        table,
+        field,
+        &["uint64", "uint32", "uint16", "uint8", "bytes"],
+    )?;
+    let width = field_type.get_width();
+
+    if width < 64 && value >> width != 0 {
+        return Err(format!(
+            "value {value} of field {field} exceeds its width of {width} bits"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Returns the common name of the requests of a single call.
+///
+/// Returns [MixedRequestNames](RBFRTError::MixedRequestNames) if the requests refer to different objects.
+fn single_name<'a>(mut names: impl Iterator<Item = &'a str>) -> Result<String, RBFRTError> {
+    let Some(first) = names.next() else {
+        return Err(RequestEmpty {});
+    };
+
+    match names.find(|n| *n != first) {
+        Some(other) => Err(MixedRequestNames {
+            first: first.to_owned(),
+            other: other.to_owned(),
+        }),
+        None => Ok(first.to_owned()),
+    }
+}
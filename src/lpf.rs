@@ -0,0 +1,300 @@
+/* Copyright 2023-present University of Tuebingen, Chair of Communication Networks
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/*
+ * Steffen Lindner (steffen.lindner@uni-tuebingen.de)
+ */
+
+use crate::error::RBFRTError;
+use crate::table::{ActionData, MatchValue, TableEntry, ToBytes};
+use prost::Message;
+use std::collections::HashMap;
+
+/// LPF index type.
+pub type IndexType = u32;
+
+pub(crate) const LPF_INDEX: &str = "$LPF_INDEX";
+pub(crate) const LPF_SPEC_TYPE: &str = "$LPF_SPEC_TYPE";
+pub(crate) const LPF_SPEC_GAIN_TIME_CONSTANT_NS: &str = "$LPF_SPEC_GAIN_TIME_CONSTANT_NS";
+pub(crate) const LPF_SPEC_DECAY_TIME_CONSTANT_NS: &str = "$LPF_SPEC_DECAY_TIME_CONSTANT_NS";
+pub(crate) const LPF_SPEC_OUT_SCALE_DOWN_FACTOR: &str = "$LPF_SPEC_OUT_SCALE_DOWN_FACTOR";
+
+/// Type of a low-pass filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LpfType {
+    /// Filters the rate of the input values.
+    Rate,
+    /// Filters the input values.
+    Sample,
+}
+
+impl LpfType {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            LpfType::Rate => "RATE",
+            LpfType::Sample => "SAMPLE",
+        }
+    }
+}
+
+/// Represents the configuration of a low-pass filter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LpfSpec {
+    /// Type of the filter.
+    pub lpf_type: LpfType,
+    /// Time constant in nanoseconds if the input value increases.
+    pub gain_time_constant_ns: f32,
+    /// Time constant in nanoseconds if the input value decreases.
+    pub decay_time_constant_ns: f32,
+    /// Scale down factor of the output value.
+    pub output_scale_down_factor: u32,
+}
+
+impl LpfSpec {
+    /// Parses the [LpfSpec] from the data fields of a [TableEntry].
+    ///
+    /// Returns [InvalidLpfSpec](RBFRTError::InvalidLpfSpec) if a field of the spec is missing or malformed.
+    fn parse(entry: &TableEntry) -> Result<LpfSpec, RBFRTError> {
+        let lpf_type =
+            String::decode(field(entry, LPF_SPEC_TYPE)?.get_data().as_slice()).map_err(|e| {
+                RBFRTError::InvalidLpfSpec {
+                    message: format!(
+                        "field {LPF_SPEC_TYPE} of {} is malformed: {e}",
+                        entry.table_name
+                    ),
+                }
+            })?;
+
+        Ok(LpfSpec {
+            lpf_type: match lpf_type.as_str() {
+                "SAMPLE" => LpfType::Sample,
+                _ => LpfType::Rate,
+            },
+            gain_time_constant_ns: decode_float(entry, LPF_SPEC_GAIN_TIME_CONSTANT_NS)?,
+            decay_time_constant_ns: decode_float(entry, LPF_SPEC_DECAY_TIME_CONSTANT_NS)?,
+            output_scale_down_factor: field(entry, LPF_SPEC_OUT_SCALE_DOWN_FACTOR)?.as_u32(),
+        })
+    }
+}
+
+/// Represents the configuration of a direct or indirect low-pass filter.
+#[derive(Debug, Clone)]
+pub struct Lpf {
+    /// name of the filter or the match-action table of a direct filter
+    name: String,
+    /// entries of the filter
+    entries: Vec<LpfEntry>,
+}
+
+impl Lpf {
+    /// Returns the `name` of the filter.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns all `entries` of the filter.
+    pub fn entries(&self) -> &Vec<LpfEntry> {
+        &self.entries
+    }
+
+    /// Returns the [LpfEntry] at a specific `index` of an indirect filter.
+    pub fn get(&self, index: IndexType) -> Option<&LpfEntry> {
+        self.entries.iter().find(|e| e.index == Some(index))
+    }
+
+    /// Parses [TableEntries](TableEntry) into [LpfEntries](LpfEntry).
+    ///
+    /// Returns [InvalidLpfSpec](RBFRTError::InvalidLpfSpec) if an entry has no complete [LpfSpec].
+    pub(crate) fn parse_lpf_entries(
+        entries: Vec<TableEntry>,
+        name: &str,
+    ) -> Result<Lpf, RBFRTError> {
+        let entries = entries
+            .into_iter()
+            .map(|e| {
+                let spec = LpfSpec::parse(&e)?;
+                let mut match_keys = e.match_keys;
+
+                let index = match_keys
+                    .remove(LPF_INDEX)
+                    .map(|i| i.get_exact_value().to_u32());
+
+                Ok(LpfEntry {
+                    index,
+                    match_keys,
+                    spec,
+                })
+            })
+            .collect::<Result<Vec<LpfEntry>, RBFRTError>>()?;
+
+        Ok(Lpf {
+            name: name.to_owned(),
+            entries,
+        })
+    }
+}
+
+/// Represents the configuration of a low-pass filter at a specific index or of a direct filter of a table entry.
+#[derive(Debug, Clone)]
+pub struct LpfEntry {
+    index: Option<IndexType>,
+    match_keys: HashMap<String, MatchValue>,
+    spec: LpfSpec,
+}
+
+impl LpfEntry {
+    /// Returns the `index` of an indirect filter entry.
+    pub fn get_index(&self) -> Option<IndexType> {
+        self.index
+    }
+
+    /// Returns the match keys of the table entry of a direct filter.
+    pub fn get_match_keys(&self) -> &HashMap<String, MatchValue> {
+        &self.match_keys
+    }
+
+    /// Returns the [LpfSpec] of the entry.
+    pub fn get_spec(&self) -> &LpfSpec {
+        &self.spec
+    }
+}
+
+/// Represents a low-pass filter request.
+///
+/// Indirect filters are addressed by their [index](Request::index), direct filters by the [match keys](Request::match_key) of the table entry.
+///
+/// # Example
+///
+/// ```
+/// use rbfrt::lpf::{LpfSpec, LpfType, Request};
+///
+/// let req = Request::new("ingress.queue_lpf").index(2).spec(LpfSpec {
+///     lpf_type: LpfType::Sample,
+///     gain_time_constant_ns: 1000.0,
+///     decay_time_constant_ns: 1000.0,
+///     output_scale_down_factor: 0,
+/// });
+///
+/// assert!(req.get_spec().is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Request {
+    name: String,
+    index: Option<IndexType>,
+    match_keys: HashMap<String, MatchValue>,
+    spec: Option<LpfSpec>,
+    pipe: Option<u32>,
+    from_hw: bool,
+}
+
+impl Request {
+    /// Creates a new [Request] for the filter with the given `name`.
+    ///
+    /// For direct filters, `name` is the name of the match-action table.
+    pub fn new(name: &str) -> Request {
+        Request {
+            name: name.to_owned(),
+            index: None,
+            match_keys: HashMap::new(),
+            spec: None,
+            pipe: None,
+            from_hw: false,
+        }
+    }
+
+    /// Returns a new [Request] with the `index` of an indirect filter.
+    pub fn index(self, index: IndexType) -> Request {
+        Request {
+            index: Some(index),
+            ..self
+        }
+    }
+
+    /// Adds a match key of the table entry of a direct filter.
+    pub fn match_key(mut self, name: &str, match_value: MatchValue) -> Request {
+        self.match_keys.insert(name.to_owned(), match_value);
+        self
+    }
+
+    /// Sets the [LpfSpec] to write.
+    pub fn spec(self, spec: LpfSpec) -> Request {
+        Request {
+            spec: Some(spec),
+            ..self
+        }
+    }
+
+    /// Sets the pipe of the filter.
+    pub fn pipe(self, pipe: u32) -> Request {
+        Request {
+            pipe: Some(pipe),
+            ..self
+        }
+    }
+
+    /// Sets if the filter is read from the hardware instead of the software shadow of the switch.
+    pub fn from_hw(self, from_hw: bool) -> Request {
+        Request { from_hw, ..self }
+    }
+
+    /// Returns the filter's `name` of the [Request].
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the [LpfSpec] of the [Request], if set.
+    pub fn get_spec(&self) -> Option<&LpfSpec> {
+        self.spec.as_ref()
+    }
+
+    /// Returns the `index` of the [Request].
+    pub fn get_index(&self) -> &Option<IndexType> {
+        &self.index
+    }
+
+    /// Returns the match keys of the [Request].
+    pub fn get_match_keys(&self) -> &HashMap<String, MatchValue> {
+        &self.match_keys
+    }
+
+    /// Returns the pipe of the [Request], if set.
+    pub fn get_pipe(&self) -> Option<u32> {
+        self.pipe
+    }
+
+    /// Returns if the filter is read from the hardware.
+    pub fn is_from_hw(&self) -> bool {
+        self.from_hw
+    }
+}
+
+/// Decodes the float data field `name` of a [TableEntry].
+fn decode_float(entry: &TableEntry, name: &str) -> Result<f32, RBFRTError> {
+    f32::decode(field(entry, name)?.get_data().as_slice()).map_err(|e| RBFRTError::InvalidLpfSpec {
+        message: format!("field {name} of {} is malformed: {e}", entry.table_name),
+    })
+}
+
+/// Returns the data field `name` of a [TableEntry].
+fn field<'a>(entry: &'a TableEntry, name: &str) -> Result<&'a ActionData, RBFRTError> {
+    entry
+        .get_action_data(name)
+        .map_err(|_| RBFRTError::InvalidLpfSpec {
+            message: format!(
+                "entry of low-pass filter {} has no field {name}",
+                entry.table_name
+            ),
+        })
+}
@@ -0,0 +1,436 @@
+/* Copyright 2023-present University of Tuebingen, Chair of Communication Networks
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/*
+ * Steffen Lindner (steffen.lindner@uni-tuebingen.de)
+ */
+
+use crate::error::RBFRTError;
+use crate::table::{MatchValue, TableEntry, ToBytes};
+use std::collections::HashMap;
+
+/// Meter index type.
+pub type IndexType = u32;
+
+/// Name of the match key of indirect meters.
+pub(crate) const METER_INDEX: &str = "$METER_INDEX";
+
+pub(crate) const METER_SPEC_CIR_KBPS: &str = "$METER_SPEC_CIR_KBPS";
+pub(crate) const METER_SPEC_PIR_KBPS: &str = "$METER_SPEC_PIR_KBPS";
+pub(crate) const METER_SPEC_CBS_KBITS: &str = "$METER_SPEC_CBS_KBITS";
+pub(crate) const METER_SPEC_PBS_KBITS: &str = "$METER_SPEC_PBS_KBITS";
+pub(crate) const METER_SPEC_CIR_PPS: &str = "$METER_SPEC_CIR_PPS";
+pub(crate) const METER_SPEC_PIR_PPS: &str = "$METER_SPEC_PIR_PPS";
+pub(crate) const METER_SPEC_CBS_PKTS: &str = "$METER_SPEC_CBS_PKTS";
+pub(crate) const METER_SPEC_PBS_PKTS: &str = "$METER_SPEC_PBS_PKTS";
+
+/// Represents an information or packet rate.
+///
+/// # Example
+///
+/// ```
+/// use rbfrt::meter::Rate;
+///
+/// assert_eq!(Rate::Mbps(10).to_kbps(), Some(10_000));
+/// assert_eq!(Rate::Bps(1500).to_kbps(), Some(2));
+/// assert_eq!(Rate::Pps(100).to_kbps(), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rate {
+    /// Bits per second.
+    Bps(u64),
+    /// Kilobits per second.
+    Kbps(u64),
+    /// Megabits per second.
+    Mbps(u64),
+    /// Packets per second.
+    Pps(u64),
+}
+
+impl Rate {
+    /// Returns the rate in kilobits per second, rounded up.
+    ///
+    /// Returns `None` for packet rates.
+    pub fn to_kbps(&self) -> Option<u64> {
+        match self {
+            Rate::Bps(bps) => Some(bps.div_ceil(1000)),
+            Rate::Kbps(kbps) => Some(*kbps),
+            Rate::Mbps(mbps) => Some(mbps.saturating_mul(1000)),
+            Rate::Pps(_) => None,
+        }
+    }
+
+    /// Returns the rate in packets per second.
+    ///
+    /// Returns `None` for information rates.
+    pub fn to_pps(&self) -> Option<u64> {
+        match self {
+            Rate::Pps(pps) => Some(*pps),
+            _ => None,
+        }
+    }
+}
+
+/// Represents the configuration of a two-rate three-color meter.
+///
+/// # Example
+///
+/// ```
+/// use rbfrt::meter::{MeterSpec, Rate};
+///
+/// let spec = MeterSpec::bytes(Rate::Mbps(100), Rate::Mbps(200), 1000, 2000).unwrap();
+///
+/// assert_eq!(spec.cir(), Rate::Kbps(100_000));
+/// assert!(MeterSpec::bytes(Rate::Pps(100), Rate::Pps(200), 10, 20).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeterSpec {
+    /// Meter that measures the information rate.
+    Bytes {
+        /// Committed information rate in kilobits per second.
+        cir_kbps: u64,
+        /// Peak information rate in kilobits per second.
+        pir_kbps: u64,
+        /// Committed burst size in kilobits.
+        cbs_kbits: u64,
+        /// Peak burst size in kilobits.
+        pbs_kbits: u64,
+    },
+    /// Meter that measures the packet rate.
+    Packets {
+        /// Committed packet rate in packets per second.
+        cir_pps: u64,
+        /// Peak packet rate in packets per second.
+        pir_pps: u64,
+        /// Committed burst size in packets.
+        cbs_pkts: u64,
+        /// Peak burst size in packets.
+        pbs_pkts: u64,
+    },
+}
+
+impl MeterSpec {
+    /// Creates a [MeterSpec] for a byte-based meter.
+    ///
+    /// Returns [InvalidMeterSpec](RBFRTError::InvalidMeterSpec) if `cir` or `pir` is a packet rate
+    /// or does not fit into 64 bits in kilobits per second.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rbfrt::meter::{MeterSpec, Rate};
+    ///
+    /// assert!(MeterSpec::bytes(Rate::Mbps(100), Rate::Mbps(200), 1000, 2000).is_ok());
+    /// assert!(MeterSpec::bytes(Rate::Mbps(u64::MAX), Rate::Mbps(200), 1000, 2000).is_err());
+    /// ```
+    pub fn bytes(
+        cir: Rate,
+        pir: Rate,
+        cbs_kbits: u64,
+        pbs_kbits: u64,
+    ) -> Result<MeterSpec, RBFRTError> {
+        for rate in [&cir, &pir] {
+            if let Rate::Mbps(mbps) = rate {
+                if mbps.checked_mul(1000).is_none() {
+                    return Err(RBFRTError::InvalidMeterSpec {
+                        message: format!("rate of {mbps} Mbps overflows the meter spec"),
+                    });
+                }
+            }
+        }
+
+        match (cir.to_kbps(), pir.to_kbps()) {
+            (Some(cir_kbps), Some(pir_kbps)) => Ok(MeterSpec::Bytes {
+                cir_kbps,
+                pir_kbps,
+                cbs_kbits,
+                pbs_kbits,
+            }),
+            _ => Err(RBFRTError::InvalidMeterSpec {
+                message: "byte-based meters require information rates".to_owned(),
+            }),
+        }
+    }
+
+    /// Creates a [MeterSpec] for a packet-based meter.
+    pub fn packets(cir_pps: u64, pir_pps: u64, cbs_pkts: u64, pbs_pkts: u64) -> MeterSpec {
+        MeterSpec::Packets {
+            cir_pps,
+            pir_pps,
+            cbs_pkts,
+            pbs_pkts,
+        }
+    }
+
+    /// Returns the committed rate.
+    pub fn cir(&self) -> Rate {
+        match self {
+            MeterSpec::Bytes { cir_kbps, .. } => Rate::Kbps(*cir_kbps),
+            MeterSpec::Packets { cir_pps, .. } => Rate::Pps(*cir_pps),
+        }
+    }
+
+    /// Returns the peak rate.
+    pub fn pir(&self) -> Rate {
+        match self {
+            MeterSpec::Bytes { pir_kbps, .. } => Rate::Kbps(*pir_kbps),
+            MeterSpec::Packets { pir_pps, .. } => Rate::Pps(*pir_pps),
+        }
+    }
+
+    /// Returns whether the meter measures packets instead of bytes.
+    pub fn is_packet_based(&self) -> bool {
+        matches!(self, MeterSpec::Packets { .. })
+    }
+
+    /// Returns the names and values of the data fields of the meter spec.
+    pub(crate) fn fields(&self) -> [(&'static str, u64); 4] {
+        match *self {
+            MeterSpec::Bytes {
+                cir_kbps,
+                pir_kbps,
+                cbs_kbits,
+                pbs_kbits,
+            } => [
+                (METER_SPEC_CIR_KBPS, cir_kbps),
+                (METER_SPEC_PIR_KBPS, pir_kbps),
+                (METER_SPEC_CBS_KBITS, cbs_kbits),
+                (METER_SPEC_PBS_KBITS, pbs_kbits),
+            ],
+            MeterSpec::Packets {
+                cir_pps,
+                pir_pps,
+                cbs_pkts,
+                pbs_pkts,
+            } => [
+                (METER_SPEC_CIR_PPS, cir_pps),
+                (METER_SPEC_PIR_PPS, pir_pps),
+                (METER_SPEC_CBS_PKTS, cbs_pkts),
+                (METER_SPEC_PBS_PKTS, pbs_pkts),
+            ],
+        }
+    }
+
+    /// Parses the [MeterSpec] from the data fields of a [TableEntry].
+    ///
+    /// Returns [InvalidMeterSpec](RBFRTError::InvalidMeterSpec) if a field of the spec is missing.
+    fn parse(entry: &TableEntry) -> Result<MeterSpec, RBFRTError> {
+        let get = |name: &str| match entry.get_action_data(name) {
+            Ok(data) => Ok(data.as_u64()),
+            Err(_) => Err(RBFRTError::InvalidMeterSpec {
+                message: format!("entry of meter {} has no field {name}", entry.table_name),
+            }),
+        };
+
+        if entry.has_action_data(METER_SPEC_CIR_KBPS) {
+            Ok(MeterSpec::Bytes {
+                cir_kbps: get(METER_SPEC_CIR_KBPS)?,
+                pir_kbps: get(METER_SPEC_PIR_KBPS)?,
+                cbs_kbits: get(METER_SPEC_CBS_KBITS)?,
+                pbs_kbits: get(METER_SPEC_PBS_KBITS)?,
+            })
+        } else {
+            Ok(MeterSpec::Packets {
+                cir_pps: get(METER_SPEC_CIR_PPS)?,
+                pir_pps: get(METER_SPEC_PIR_PPS)?,
+                cbs_pkts: get(METER_SPEC_CBS_PKTS)?,
+                pbs_pkts: get(METER_SPEC_PBS_PKTS)?,
+            })
+        }
+    }
+}
+
+/// Represents the configuration of a direct or indirect meter.
+#[derive(Debug, Clone)]
+pub struct Meter {
+    /// name of the meter or the match-action table of a direct meter
+    name: String,
+    /// entries of the meter
+    entries: Vec<MeterEntry>,
+}
+
+impl Meter {
+    /// Creates a new [Meter] with the provided `name` and `entries`.
+    pub fn new(name: &str, entries: Vec<MeterEntry>) -> Meter {
+        Meter {
+            name: name.to_owned(),
+            entries,
+        }
+    }
+
+    /// Returns the `name` of the meter.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns all `entries` of the meter.
+    pub fn entries(&self) -> &Vec<MeterEntry> {
+        &self.entries
+    }
+
+    /// Returns the [MeterEntry] at a specific `index` of an indirect meter.
+    pub fn get(&self, index: IndexType) -> Option<&MeterEntry> {
+        self.entries.iter().find(|e| e.index == Some(index))
+    }
+
+    /// Parses [TableEntries](TableEntry) into [MeterEntries](MeterEntry).
+    ///
+    /// Returns [InvalidMeterSpec](RBFRTError::InvalidMeterSpec) if an entry has no complete [MeterSpec].
+    pub(crate) fn parse_meter_entries(
+        entries: Vec<TableEntry>,
+        name: &str,
+    ) -> Result<Meter, RBFRTError> {
+        let entries = entries
+            .into_iter()
+            .map(|e| {
+                let spec = MeterSpec::parse(&e)?;
+                let mut match_keys = e.match_keys;
+
+                let index = match_keys
+                    .remove(METER_INDEX)
+                    .map(|i| i.get_exact_value().to_u32());
+
+                Ok(MeterEntry {
+                    index,
+                    match_keys,
+                    spec,
+                })
+            })
+            .collect::<Result<Vec<MeterEntry>, RBFRTError>>()?;
+
+        Ok(Meter::new(name, entries))
+    }
+}
+
+/// Represents the configuration of a meter at a specific index or of a direct meter of a table entry.
+#[derive(Debug, Clone)]
+pub struct MeterEntry {
+    index: Option<IndexType>,
+    match_keys: HashMap<String, MatchValue>,
+    spec: MeterSpec,
+}
+
+impl MeterEntry {
+    /// Returns the `index` of an indirect meter entry.
+    pub fn get_index(&self) -> Option<IndexType> {
+        self.index
+    }
+
+    /// Returns the match keys of the table entry of a direct meter.
+    pub fn get_match_keys(&self) -> &HashMap<String, MatchValue> {
+        &self.match_keys
+    }
+
+    /// Returns the [MeterSpec] of the entry.
+    pub fn get_spec(&self) -> &MeterSpec {
+        &self.spec
+    }
+}
+
+/// Represents a meter request.
+///
+/// Indirect meters are addressed by their [index](Request::index), direct meters by the [match keys](Request::match_key) of the table entry.
+#[derive(Debug, Clone)]
+pub struct Request {
+    name: String,
+    index: Option<IndexType>,
+    match_keys: HashMap<String, MatchValue>,
+    spec: Option<MeterSpec>,
+    pipe: Option<u32>,
+    from_hw: bool,
+}
+
+impl Request {
+    /// Creates a new [Request] for the meter with the given `name`.
+    ///
+    /// For direct meters, `name` is the name of the match-action table.
+    pub fn new(name: &str) -> Request {
+        Request {
+            name: name.to_owned(),
+            index: None,
+            match_keys: HashMap::new(),
+            spec: None,
+            pipe: None,
+            from_hw: false,
+        }
+    }
+
+    /// Returns a new [Request] with the `index` of an indirect meter.
+    pub fn index(self, index: IndexType) -> Request {
+        Request {
+            index: Some(index),
+            ..self
+        }
+    }
+
+    /// Adds a match key of the table entry of a direct meter.
+    pub fn match_key(mut self, name: &str, match_value: MatchValue) -> Request {
+        self.match_keys.insert(name.to_owned(), match_value);
+        self
+    }
+
+    /// Sets the [MeterSpec] to write.
+    pub fn spec(self, spec: MeterSpec) -> Request {
+        Request {
+            spec: Some(spec),
+            ..self
+        }
+    }
+
+    /// Sets the pipe of the meter.
+    ///
+    /// Only tables with an asymmetric pipe scope can be addressed per pipe, see [set_entry_scope](crate::SwitchConnection::set_entry_scope).
+    pub fn pipe(self, pipe: u32) -> Request {
+        Request {
+            pipe: Some(pipe),
+            ..self
+        }
+    }
+
+    /// Sets if the meter is read from the hardware instead of the software shadow of the switch.
+    pub fn from_hw(self, from_hw: bool) -> Request {
+        Request { from_hw, ..self }
+    }
+
+    /// Returns the meter's `name` of the [Request].
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the meter's `index` in the [Request].
+    pub fn get_index(&self) -> &Option<IndexType> {
+        &self.index
+    }
+
+    /// Returns the match keys of the [Request].
+    pub fn get_match_keys(&self) -> &HashMap<String, MatchValue> {
+        &self.match_keys
+    }
+
+    /// Returns the [MeterSpec] of the [Request], if set.
+    pub fn get_spec(&self) -> Option<&MeterSpec> {
+        self.spec.as_ref()
+    }
+
+    /// Returns the pipe of the [Request], if set.
+    pub fn get_pipe(&self) -> Option<u32> {
+        self.pipe
+    }
+
+    /// Returns if the meter is read from the hardware.
+    pub fn is_from_hw(&self) -> bool {
+        self.from_hw
+    }
+}
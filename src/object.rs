@@ -0,0 +1,182 @@
+/* Copyright 2023-present University of Tuebingen, Chair of Communication Networks
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/*
+ * Steffen Lindner (steffen.lindner@uni-tuebingen.de)
+ */
+
+use crate::bfrt_proto;
+use crate::bfrt_proto::object_id::{
+    table_object, ActionName, DataFieldName, KeyFieldName, LearnObject, Object, TableObject,
+};
+use std::fmt::{Display, Formatter};
+
+/// Name of a BF Runtime object whose id can be resolved by the switch.
+///
+/// See [resolve_object_id](crate::SwitchConnection::resolve_object_id).
+///
+/// # Example
+///
+/// ```
+/// use rbfrt::object::ObjectName;
+///
+/// let name = ObjectName::action("ingress.exact_forward", "ingress.do_forward");
+///
+/// assert_eq!(name.to_string(), "ingress.exact_forward/ingress.do_forward");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObjectName {
+    /// A table.
+    Table { table: String },
+    /// An action of a table.
+    Action { table: String, action: String },
+    /// A match key field of a table.
+    KeyField { table: String, field: String },
+    /// A data field of a table, either a parameter of an `action` or a singleton field if `action` is [None].
+    DataField {
+        table: String,
+        action: Option<String>,
+        field: String,
+    },
+    /// A learn filter.
+    Learn { learn: String },
+    /// A field of a learn filter.
+    LearnField { learn: String, field: String },
+}
+
+impl ObjectName {
+    /// Creates the name of the `table`.
+    pub fn table(table: &str) -> ObjectName {
+        ObjectName::Table {
+            table: table.to_owned(),
+        }
+    }
+
+    /// Creates the name of the `action` of the `table`.
+    pub fn action(table: &str, action: &str) -> ObjectName {
+        ObjectName::Action {
+            table: table.to_owned(),
+            action: action.to_owned(),
+        }
+    }
+
+    /// Creates the name of the match key `field` of the `table`.
+    pub fn key_field(table: &str, field: &str) -> ObjectName {
+        ObjectName::KeyField {
+            table: table.to_owned(),
+            field: field.to_owned(),
+        }
+    }
+
+    /// Creates the name of the data `field` of the `table`.
+    ///
+    /// `action` is [None] for singleton fields that are not associated with an action.
+    pub fn data_field(table: &str, action: Option<&str>, field: &str) -> ObjectName {
+        ObjectName::DataField {
+            table: table.to_owned(),
+            action: action.map(|a| a.to_owned()),
+            field: field.to_owned(),
+        }
+    }
+
+    /// Creates the name of the `learn` filter.
+    pub fn learn(learn: &str) -> ObjectName {
+        ObjectName::Learn {
+            learn: learn.to_owned(),
+        }
+    }
+
+    /// Creates the name of the `field` of the `learn` filter.
+    pub fn learn_field(learn: &str, field: &str) -> ObjectName {
+        ObjectName::LearnField {
+            learn: learn.to_owned(),
+            field: field.to_owned(),
+        }
+    }
+
+    pub(crate) fn to_proto(&self) -> bfrt_proto::ObjectId {
+        let table = |table: &str, names: Option<table_object::Names>| {
+            Object::TableObject(TableObject {
+                table_name: table.to_owned(),
+                names,
+            })
+        };
+
+        let object = match self {
+            ObjectName::Table { table: t } => table(t, None),
+            ObjectName::Action { table: t, action } => table(
+                t,
+                Some(table_object::Names::ActionName(ActionName {
+                    action: action.to_owned(),
+                })),
+            ),
+            ObjectName::KeyField { table: t, field } => table(
+                t,
+                Some(table_object::Names::KeyFieldName(KeyFieldName {
+                    field: field.to_owned(),
+                })),
+            ),
+            ObjectName::DataField {
+                table: t,
+                action,
+                field,
+            } => table(
+                t,
+                Some(table_object::Names::DataFieldName(DataFieldName {
+                    action: action.clone().unwrap_or_default(),
+                    field: field.to_owned(),
+                })),
+            ),
+            ObjectName::Learn { learn } => Object::LearnObject(LearnObject {
+                learn_name: learn.to_owned(),
+                data_field_name: None,
+            }),
+            ObjectName::LearnField { learn, field } => Object::LearnObject(LearnObject {
+                learn_name: learn.to_owned(),
+                data_field_name: Some(DataFieldName {
+                    action: String::new(),
+                    field: field.to_owned(),
+                }),
+            }),
+        };
+
+        bfrt_proto::ObjectId {
+            id: 0,
+            object: Some(object),
+        }
+    }
+}
+
+impl Display for ObjectName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ObjectName::Table { table } => write!(f, "{table}"),
+            ObjectName::Action { table, action } => write!(f, "{table}/{action}"),
+            ObjectName::KeyField { table, field } => write!(f, "{table}/{field}"),
+            ObjectName::DataField {
+                table,
+                action: Some(action),
+                field,
+            } => write!(f, "{table}/{action}/{field}"),
+            ObjectName::DataField {
+                table,
+                action: None,
+                field,
+            } => write!(f, "{table}/{field}"),
+            ObjectName::Learn { learn } => write!(f, "{learn}"),
+            ObjectName::LearnField { learn, field } => write!(f, "{learn}/{field}"),
+        }
+    }
+}
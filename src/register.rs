@@ -18,7 +18,7 @@
  */
 
 use crate::table;
-use crate::table::{TableEntry, ToBytes};
+use crate::table::{Direction, TableEntry, ToBytes};
 use std::collections::HashMap;
 
 /// Register index type.
@@ -131,6 +131,9 @@ pub struct Request {
     name: String,
     index: Option<IndexType>,
     data: HashMap<String, Vec<u8>>,
+    from_hw: bool,
+    direction: Option<Direction>,
+    parser: Option<u32>,
 }
 
 impl Request {
@@ -140,6 +143,9 @@ impl Request {
             name: name.to_owned(),
             index: None,
             data: HashMap::new(),
+            from_hw: false,
+            direction: None,
+            parser: None,
         }
     }
 
@@ -151,6 +157,44 @@ impl Request {
         }
     }
 
+    /// Sets if the register is read from the hardware instead of the software shadow of the switch.
+    ///
+    /// Only used for reading registers.
+    pub fn from_hw(self, from_hw: bool) -> Request {
+        Request { from_hw, ..self }
+    }
+
+    /// Returns if the register is read from the hardware.
+    pub fn is_from_hw(&self) -> bool {
+        self.from_hw
+    }
+
+    /// Sets the [Direction] of the register.
+    pub fn direction(self, direction: Direction) -> Request {
+        Request {
+            direction: Some(direction),
+            ..self
+        }
+    }
+
+    /// Returns the [Direction] of the register, if set.
+    pub fn get_direction(&self) -> Option<Direction> {
+        self.direction
+    }
+
+    /// Sets the parser of the register.
+    pub fn parser(self, parser: u32) -> Request {
+        Request {
+            parser: Some(parser),
+            ..self
+        }
+    }
+
+    /// Returns the parser of the register, if set.
+    pub fn get_parser(&self) -> Option<u32> {
+        self.parser
+    }
+
     /// Returns the register's `name` of the [Request].
     pub fn get_name(&self) -> &str {
         &self.name
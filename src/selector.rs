@@ -0,0 +1,248 @@
+/* Copyright 2023-present University of Tuebingen, Chair of Communication Networks
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/*
+ * Steffen Lindner (steffen.lindner@uni-tuebingen.de)
+ */
+
+use crate::error::RBFRTError;
+use crate::table::{ActionData, TableEntry, ToBytes};
+
+/// Name of the match key of action profiles and the data field referencing a member.
+pub(crate) const ACTION_MEMBER_ID: &str = "$ACTION_MEMBER_ID";
+/// Name of the match key of selectors and the data field referencing a group.
+pub(crate) const SELECTOR_GROUP_ID: &str = "$SELECTOR_GROUP_ID";
+/// Name of the data field containing the maximum size of a group.
+pub(crate) const MAX_GROUP_SIZE: &str = "$MAX_GROUP_SIZE";
+/// Name of the data field containing the status of the members of a group.
+pub(crate) const ACTION_MEMBER_STATUS: &str = "$ACTION_MEMBER_STATUS";
+/// Name of the data field containing the weights of the members of a group.
+pub(crate) const ACTION_MEMBER_WEIGHT: &str = "$ACTION_MEMBER_WEIGHT";
+
+/// Represents a member of an action profile, i.e., an action together with its parameters.
+///
+/// # Example
+///
+/// ```
+/// use rbfrt::selector::ActionMember;
+///
+/// let member = ActionMember::new(1, "ingress.set_port").action_data("port", 128);
+///
+/// assert_eq!(member.get_id(), 1);
+/// assert_eq!(member.get_action_data_by_name("port").unwrap().as_u32(), 128);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ActionMember {
+    id: u32,
+    action: String,
+    action_data: Vec<ActionData>,
+}
+
+impl ActionMember {
+    /// Creates a new [ActionMember] with the given `id` that executes `action`.
+    pub fn new(id: u32, action: &str) -> ActionMember {
+        ActionMember {
+            id,
+            action: action.to_owned(),
+            action_data: vec![],
+        }
+    }
+
+    /// Adds a parameter of the action.
+    pub fn action_data<T: ToBytes>(mut self, name: &str, data: T) -> ActionMember {
+        self.action_data.push(ActionData::new(name, data));
+        self
+    }
+
+    /// Returns the `id` of the member.
+    pub fn get_id(&self) -> u32 {
+        self.id
+    }
+
+    /// Returns the name of the action of the member.
+    pub fn get_action_name(&self) -> &str {
+        &self.action
+    }
+
+    /// Returns the parameters of the action.
+    pub fn get_action_data(&self) -> &Vec<ActionData> {
+        &self.action_data
+    }
+
+    /// Returns the parameter of the action with the given `name`.
+    pub fn get_action_data_by_name(&self, name: &str) -> Option<&ActionData> {
+        self.action_data.iter().find(|d| d.get_key() == name)
+    }
+
+    /// Parses an [ActionMember] from an entry of an action profile.
+    pub(crate) fn parse(entry: TableEntry) -> Result<ActionMember, RBFRTError> {
+        let id = entry.get_key(ACTION_MEMBER_ID)?.get_exact_value().to_u32();
+
+        Ok(ActionMember {
+            id,
+            action: entry.action,
+            action_data: entry.action_data,
+        })
+    }
+}
+
+/// Represents a member of a selector group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GroupMember {
+    /// Id of the action profile member.
+    pub member_id: u32,
+    /// Whether the member is selected for traffic.
+    pub enabled: bool,
+    /// Weight of the member.
+    ///
+    /// Weights other than `1` require a selector that supports the `$ACTION_MEMBER_WEIGHT` data field.
+    pub weight: u32,
+}
+
+impl GroupMember {
+    /// Creates a new enabled [GroupMember] with weight `1`.
+    pub fn new(member_id: u32) -> GroupMember {
+        GroupMember {
+            member_id,
+            enabled: true,
+            weight: 1,
+        }
+    }
+
+    /// Sets the `weight` of the member.
+    pub fn weight(self, weight: u32) -> GroupMember {
+        GroupMember { weight, ..self }
+    }
+
+    /// Sets whether the member is `enabled`.
+    pub fn enabled(self, enabled: bool) -> GroupMember {
+        GroupMember { enabled, ..self }
+    }
+}
+
+/// Represents a group of an action selector.
+///
+/// # Example
+///
+/// ```
+/// use rbfrt::selector::{GroupMember, SelectorGroup};
+///
+/// let group = SelectorGroup::new(10, 4)
+///     .member(GroupMember::new(1))
+///     .member(GroupMember::new(2).enabled(false));
+///
+/// assert_eq!(group.get_members().len(), 2);
+/// assert_eq!(group.get_enabled_members().count(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectorGroup {
+    id: u32,
+    max_size: u32,
+    members: Vec<GroupMember>,
+}
+
+impl SelectorGroup {
+    /// Creates a new empty [SelectorGroup] with the given `id` that holds at most `max_size` members.
+    pub fn new(id: u32, max_size: u32) -> SelectorGroup {
+        SelectorGroup {
+            id,
+            max_size,
+            members: vec![],
+        }
+    }
+
+    /// Adds a [GroupMember] to the group.
+    pub fn member(mut self, member: GroupMember) -> SelectorGroup {
+        self.members.push(member);
+        self
+    }
+
+    /// Returns the `id` of the group.
+    pub fn get_id(&self) -> u32 {
+        self.id
+    }
+
+    /// Returns the maximum number of members of the group.
+    pub fn get_max_size(&self) -> u32 {
+        self.max_size
+    }
+
+    /// Returns the members of the group.
+    pub fn get_members(&self) -> &Vec<GroupMember> {
+        &self.members
+    }
+
+    /// Returns the enabled members of the group.
+    pub fn get_enabled_members(&self) -> impl Iterator<Item = &GroupMember> {
+        self.members.iter().filter(|m| m.enabled)
+    }
+
+    /// Returns the [GroupMember] with the given `member_id`.
+    pub fn get_member(&self, member_id: u32) -> Option<&GroupMember> {
+        self.members.iter().find(|m| m.member_id == member_id)
+    }
+
+    /// Returns whether any of the members has a weight other than `1`.
+    pub(crate) fn is_weighted(&self) -> bool {
+        self.members.iter().any(|m| m.weight != 1)
+    }
+
+    /// Returns a mutable reference to the [GroupMember] with the given `member_id`.
+    pub(crate) fn get_member_mut(&mut self, member_id: u32) -> Option<&mut GroupMember> {
+        self.members.iter_mut().find(|m| m.member_id == member_id)
+    }
+
+    /// Removes the members with the given `member_ids` and returns whether any member was removed.
+    pub(crate) fn remove_members(&mut self, member_ids: &[u32]) -> bool {
+        let len = self.members.len();
+        self.members.retain(|m| !member_ids.contains(&m.member_id));
+        len != self.members.len()
+    }
+
+    /// Parses a [SelectorGroup] from an entry of a selector.
+    pub(crate) fn parse(entry: TableEntry) -> Result<SelectorGroup, RBFRTError> {
+        let id = entry.get_key(SELECTOR_GROUP_ID)?.get_exact_value().to_u32();
+        let max_size = entry.get_action_data(MAX_GROUP_SIZE)?.as_u32();
+
+        let member_ids = entry
+            .get_action_data(ACTION_MEMBER_ID)
+            .map(|d| d.get_data().to_int_arr())
+            .unwrap_or_default();
+        let status = entry
+            .get_action_data(ACTION_MEMBER_STATUS)
+            .map(|d| d.get_data().clone())
+            .unwrap_or_default();
+        let weights = entry
+            .get_action_data(ACTION_MEMBER_WEIGHT)
+            .map(|d| d.get_data().to_int_arr())
+            .unwrap_or_default();
+
+        let members = member_ids
+            .iter()
+            .enumerate()
+            .map(|(i, member_id)| GroupMember {
+                member_id: *member_id,
+                enabled: status.get(i).map(|s| *s > 0).unwrap_or(true),
+                weight: weights.get(i).copied().unwrap_or(1),
+            })
+            .collect();
+
+        Ok(SelectorGroup {
+            id,
+            max_size,
+            members,
+        })
+    }
+}
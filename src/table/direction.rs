@@ -0,0 +1,50 @@
+/* Copyright 2023-present University of Tuebingen, Chair of Communication Networks
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/*
+ * Steffen Lindner (steffen.lindner@uni-tuebingen.de)
+ */
+
+/// Direction (gress) of a table entry or register.
+///
+/// See [Request::direction](crate::table::Request::direction).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Direction {
+    /// Ingress and egress.
+    #[default]
+    All,
+    /// Ingress only.
+    Ingress,
+    /// Egress only.
+    Egress,
+}
+
+impl Direction {
+    pub(crate) fn to_proto(self) -> u32 {
+        match self {
+            Direction::All => 0xff,
+            Direction::Ingress => 0,
+            Direction::Egress => 1,
+        }
+    }
+
+    pub(crate) fn from_proto(direction: u32) -> Direction {
+        match direction {
+            0 => Direction::Ingress,
+            1 => Direction::Egress,
+            _ => Direction::All,
+        }
+    }
+}
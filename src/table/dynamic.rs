@@ -0,0 +1,135 @@
+/* Copyright 2023-present University of Tuebingen, Chair of Communication Networks
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/*
+ * Steffen Lindner (steffen.lindner@uni-tuebingen.de)
+ */
+
+use crate::table::ToBytes;
+use std::collections::HashMap;
+
+/// Represents the dynamic key mask of a table.
+///
+/// Key bits that are not set in the mask of a key field are ignored for matching.
+/// Key fields without a mask are matched completely.
+/// See [set_dyn_key_mask](crate::SwitchConnection::set_dyn_key_mask).
+///
+/// # Example
+///
+/// ```
+/// use rbfrt::table::DynKeyMask;
+///
+/// let mask = DynKeyMask::new().mask("hdr.ipv4.dst_addr", vec![255u8, 255, 255, 0]);
+///
+/// assert_eq!(mask.get_mask("hdr.ipv4.dst_addr"), Some(&vec![255u8, 255, 255, 0]));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DynKeyMask {
+    masks: HashMap<String, Vec<u8>>,
+}
+
+impl DynKeyMask {
+    /// Creates a new empty [DynKeyMask].
+    pub fn new() -> DynKeyMask {
+        DynKeyMask::default()
+    }
+
+    /// Sets the `mask` of the key field with the given `name`.
+    pub fn mask<T: ToBytes>(mut self, name: &str, mask: T) -> DynKeyMask {
+        self.masks.insert(name.to_owned(), mask.to_bytes());
+        self
+    }
+
+    /// Returns the mask of the key field with the given `name`.
+    pub fn get_mask(&self, name: &str) -> Option<&Vec<u8>> {
+        self.masks.get(name)
+    }
+
+    /// Returns the masks of all key fields.
+    pub fn get_masks(&self) -> &HashMap<String, Vec<u8>> {
+        &self.masks
+    }
+}
+
+/// Represents the dynamic hashing configuration of a table.
+///
+/// The algorithm is either given by the handle the switch uses for it, e.g., as returned by [get_dyn_hashing](crate::SwitchConnection::get_dyn_hashing),
+/// or by one of the names that [get_hash_algorithms](crate::SwitchConnection::get_hash_algorithms) returns.
+/// See [set_dyn_hashing](crate::SwitchConnection::set_dyn_hashing).
+///
+/// # Example
+///
+/// ```
+/// use rbfrt::table::DynHashing;
+///
+/// let hashing = DynHashing::new("CRC32", 42);
+///
+/// assert_eq!(hashing.get_algorithm(), Some("CRC32"));
+/// assert_eq!(hashing.get_handle(), None);
+/// assert_eq!(hashing.get_seed(), 42);
+///
+/// let hashing = DynHashing::from_handle(3, 42);
+///
+/// assert_eq!(hashing.get_handle(), Some(3));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DynHashing {
+    algorithm: Option<String>,
+    handle: Option<u32>,
+    seed: u64,
+}
+
+impl DynHashing {
+    /// Creates a new [DynHashing] with the hash `algorithm` name and `seed`.
+    pub fn new(algorithm: &str, seed: u64) -> DynHashing {
+        DynHashing {
+            algorithm: Some(algorithm.to_owned()),
+            handle: None,
+            seed,
+        }
+    }
+
+    /// Creates a new [DynHashing] with the algorithm `handle` of the switch and `seed`.
+    pub fn from_handle(handle: u32, seed: u64) -> DynHashing {
+        DynHashing {
+            algorithm: None,
+            handle: Some(handle),
+            seed,
+        }
+    }
+
+    pub(crate) fn from_switch(algorithm: Option<String>, handle: u32, seed: u64) -> DynHashing {
+        DynHashing {
+            algorithm,
+            handle: Some(handle),
+            seed,
+        }
+    }
+
+    /// Returns the name of the hash algorithm, if it is known.
+    pub fn get_algorithm(&self) -> Option<&str> {
+        self.algorithm.as_deref()
+    }
+
+    /// Returns the handle of the hash algorithm on the switch, if it is known.
+    pub fn get_handle(&self) -> Option<u32> {
+        self.handle
+    }
+
+    /// Returns the seed of the hash algorithm.
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+}
@@ -0,0 +1,148 @@
+/* Copyright 2023-present University of Tuebingen, Chair of Communication Networks
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/*
+ * Steffen Lindner (steffen.lindner@uni-tuebingen.de)
+ */
+
+use crate::bfrt_proto;
+use crate::bfrt_proto::mode::{PredefinedMode, Scope};
+
+/// Scope of a dimension of an [EntryScope].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScopeMode {
+    /// Entries are shared by all instances, e.g., all pipes.
+    #[default]
+    All,
+    /// Each instance, e.g., each pipe, has its own entries.
+    Single,
+    /// Instances are grouped by the user-defined `scope`, e.g., a bitmap of pipes per group.
+    UserDefined { scope: u64, args: u64 },
+}
+
+impl ScopeMode {
+    fn to_proto(self) -> bfrt_proto::Mode {
+        match self {
+            ScopeMode::All => bfrt_proto::Mode {
+                args: 0,
+                scope: Some(Scope::Predef(PredefinedMode::All.into())),
+            },
+            ScopeMode::Single => bfrt_proto::Mode {
+                args: 0,
+                scope: Some(Scope::Predef(PredefinedMode::Single.into())),
+            },
+            ScopeMode::UserDefined { scope, args } => bfrt_proto::Mode {
+                args,
+                scope: Some(Scope::UserDefined(scope)),
+            },
+        }
+    }
+
+    fn from_proto(mode: Option<&bfrt_proto::Mode>) -> ScopeMode {
+        match mode.and_then(|m| m.scope.map(|s| (s, m.args))) {
+            Some((Scope::Predef(p), _)) if p == PredefinedMode::Single as i32 => ScopeMode::Single,
+            Some((Scope::UserDefined(scope), args)) => ScopeMode::UserDefined { scope, args },
+            _ => ScopeMode::All,
+        }
+    }
+}
+
+/// Represents the entry scope of a table.
+///
+/// The entry scope defines whether a table is symmetric, i.e., all pipes, gresses, or parsers share the same entries, or asymmetric.
+/// See [set_entry_scope](crate::SwitchConnection::set_entry_scope).
+///
+/// # Example
+///
+/// ```
+/// use rbfrt::table::{EntryScope, ScopeMode};
+///
+/// let scope = EntryScope::new().pipe_scope(ScopeMode::Single);
+///
+/// assert!(scope.is_pipe_asymmetric());
+/// assert_eq!(scope.get_gress_scope(), ScopeMode::All);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct EntryScope {
+    gress: ScopeMode,
+    pipe: ScopeMode,
+    parser: ScopeMode,
+}
+
+impl EntryScope {
+    /// Creates a new symmetric [EntryScope].
+    pub fn new() -> EntryScope {
+        EntryScope::default()
+    }
+
+    /// Sets the scope of the ingress and egress gress.
+    pub fn gress_scope(mut self, mode: ScopeMode) -> EntryScope {
+        self.gress = mode;
+        self
+    }
+
+    /// Sets the scope of the pipes.
+    pub fn pipe_scope(mut self, mode: ScopeMode) -> EntryScope {
+        self.pipe = mode;
+        self
+    }
+
+    /// Sets the scope of the parsers.
+    pub fn parser_scope(mut self, mode: ScopeMode) -> EntryScope {
+        self.parser = mode;
+        self
+    }
+
+    /// Returns the scope of the gresses.
+    pub fn get_gress_scope(&self) -> ScopeMode {
+        self.gress
+    }
+
+    /// Returns the scope of the pipes.
+    pub fn get_pipe_scope(&self) -> ScopeMode {
+        self.pipe
+    }
+
+    /// Returns the scope of the parsers.
+    pub fn get_parser_scope(&self) -> ScopeMode {
+        self.parser
+    }
+
+    /// Returns whether entries can be addressed to a single pipe.
+    pub fn is_pipe_asymmetric(&self) -> bool {
+        self.pipe != ScopeMode::All
+    }
+
+    /// Returns whether entries can be addressed to a single gress.
+    pub fn is_gress_asymmetric(&self) -> bool {
+        self.gress != ScopeMode::All
+    }
+
+    pub(crate) fn to_proto(self) -> bfrt_proto::EntryScope {
+        bfrt_proto::EntryScope {
+            gress_scope: Some(self.gress.to_proto()),
+            pipe_scope: Some(self.pipe.to_proto()),
+            prsr_scope: Some(self.parser.to_proto()),
+        }
+    }
+
+    pub(crate) fn from_proto(scope: &bfrt_proto::EntryScope) -> EntryScope {
+        EntryScope {
+            gress: ScopeMode::from_proto(scope.gress_scope.as_ref()),
+            pipe: ScopeMode::from_proto(scope.pipe_scope.as_ref()),
+            parser: ScopeMode::from_proto(scope.prsr_scope.as_ref()),
+        }
+    }
+}
@@ -0,0 +1,159 @@
+/* Copyright 2023-present University of Tuebingen, Chair of Communication Networks
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/*
+ * Steffen Lindner (steffen.lindner@uni-tuebingen.de)
+ */
+
+use crate::bfrt_proto;
+use crate::bfrt_proto::idle_table::IdleTableMode as ProtoIdleTableMode;
+
+/// Mode of an idle table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IdleTableMode {
+    /// The hit state of the entries is polled by the controller, see [EntryHitState].
+    #[default]
+    Poll,
+    /// The switch ages out entries based on their TTL and sends [IdleTimeoutEvents](crate::util::IdleTimeoutEvent).
+    Notify,
+}
+
+/// Represents the idle timeout configuration of a table.
+///
+/// The table has to be declared with `idle_timeout = true` in the P4 program.
+/// See [enable_idle_table](crate::SwitchConnection::enable_idle_table).
+///
+/// # Example
+///
+/// ```
+/// use rbfrt::table::{IdleTable, IdleTableMode};
+///
+/// let idle_table = IdleTable::new(IdleTableMode::Notify)
+///     .ttl_query_interval(1000)
+///     .min_ttl(1000)
+///     .max_ttl(60000);
+///
+/// assert_eq!(idle_table.get_mode(), IdleTableMode::Notify);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct IdleTable {
+    mode: IdleTableMode,
+    ttl_query_interval: u32,
+    min_ttl: u32,
+    max_ttl: u32,
+    enabled: bool,
+}
+
+impl IdleTable {
+    /// Creates a new enabled [IdleTable] configuration with the given `mode`.
+    pub fn new(mode: IdleTableMode) -> IdleTable {
+        IdleTable {
+            mode,
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the interval in milliseconds in which the switch checks the TTL of the entries.
+    ///
+    /// Only used in [Notify](IdleTableMode::Notify) mode.
+    pub fn ttl_query_interval(mut self, interval_ms: u32) -> IdleTable {
+        self.ttl_query_interval = interval_ms;
+        self
+    }
+
+    /// Sets the minimum TTL in milliseconds of the entries.
+    ///
+    /// Only used in [Notify](IdleTableMode::Notify) mode.
+    pub fn min_ttl(mut self, ttl_ms: u32) -> IdleTable {
+        self.min_ttl = ttl_ms;
+        self
+    }
+
+    /// Sets the maximum TTL in milliseconds of the entries.
+    ///
+    /// Only used in [Notify](IdleTableMode::Notify) mode.
+    pub fn max_ttl(mut self, ttl_ms: u32) -> IdleTable {
+        self.max_ttl = ttl_ms;
+        self
+    }
+
+    /// Returns the [IdleTableMode].
+    pub fn get_mode(&self) -> IdleTableMode {
+        self.mode
+    }
+
+    /// Returns the TTL query interval in milliseconds.
+    pub fn get_ttl_query_interval(&self) -> u32 {
+        self.ttl_query_interval
+    }
+
+    /// Returns the minimum TTL in milliseconds.
+    pub fn get_min_ttl(&self) -> u32 {
+        self.min_ttl
+    }
+
+    /// Returns the maximum TTL in milliseconds.
+    pub fn get_max_ttl(&self) -> u32 {
+        self.max_ttl
+    }
+
+    /// Returns whether idle timeout is enabled for the table.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub(crate) fn disabled() -> IdleTable {
+        IdleTable::default()
+    }
+
+    pub(crate) fn to_proto(&self) -> bfrt_proto::IdleTable {
+        bfrt_proto::IdleTable {
+            ttl_query_interval: self.ttl_query_interval,
+            max_ttl: self.max_ttl,
+            min_ttl: self.min_ttl,
+            idle_table_mode: match self.mode {
+                IdleTableMode::Poll => ProtoIdleTableMode::IdleTablePollMode,
+                IdleTableMode::Notify => ProtoIdleTableMode::IdleTableNotifyMode,
+            }
+            .into(),
+            enable: self.enabled,
+        }
+    }
+
+    pub(crate) fn from_proto(idle_table: &bfrt_proto::IdleTable) -> IdleTable {
+        IdleTable {
+            mode: match idle_table.idle_table_mode() {
+                ProtoIdleTableMode::IdleTablePollMode => IdleTableMode::Poll,
+                ProtoIdleTableMode::IdleTableNotifyMode => IdleTableMode::Notify,
+            },
+            ttl_query_interval: idle_table.ttl_query_interval,
+            min_ttl: idle_table.min_ttl,
+            max_ttl: idle_table.max_ttl,
+            enabled: idle_table.enable,
+        }
+    }
+}
+
+/// Hit state of an entry of an idle table in [Poll](IdleTableMode::Poll) mode.
+///
+/// See [TableEntry::get_entry_hit_state](crate::table::TableEntry::get_entry_hit_state).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryHitState {
+    /// The entry was not hit since the last poll.
+    Idle,
+    /// The entry was hit since the last poll.
+    Active,
+}
@@ -18,14 +18,29 @@
  */
 pub use action_data::ActionData;
 pub use action_data::ActionDataRepeated;
+pub use direction::Direction;
+pub use dynamic::{DynHashing, DynKeyMask};
+pub use entry_scope::{EntryScope, ScopeMode};
+pub use idle_table::{EntryHitState, IdleTable, IdleTableMode};
 pub use match_value::MatchValue;
+pub use pre_device_config::PreDeviceConfig;
+pub use table_entry::Atomicity;
 pub use table_entry::Request;
 pub(crate) use table_entry::RequestType;
 pub use table_entry::TableEntry;
 pub use table_entry::TableOperation;
+pub use table_usage::TableUsage;
 pub use to_bytes::ToBytes;
+pub use transaction::Transaction;
 
 mod action_data;
+mod direction;
+mod dynamic;
+mod entry_scope;
+mod idle_table;
 mod match_value;
+mod pre_device_config;
 mod table_entry;
+mod table_usage;
 mod to_bytes;
+mod transaction;
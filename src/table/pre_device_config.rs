@@ -0,0 +1,142 @@
+/* Copyright 2023-present University of Tuebingen, Chair of Communication Networks
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/*
+ * Steffen Lindner (steffen.lindner@uni-tuebingen.de)
+ */
+
+use crate::bfrt_proto;
+
+/// Represents the device configuration of the packet replication engine (PRE).
+///
+/// Only the settings that are set are changed by [set_pre_device_config](crate::SwitchConnection::set_pre_device_config).
+///
+/// # Example
+///
+/// ```
+/// use rbfrt::table::PreDeviceConfig;
+///
+/// let config = PreDeviceConfig::new()
+///     .port_protection(true)
+///     .fast_failover(true);
+///
+/// assert_eq!(config.get_port_protection(), Some(true));
+/// assert_eq!(config.get_global_rid(), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PreDeviceConfig {
+    global_rid: Option<u32>,
+    port_protection: Option<bool>,
+    fast_failover: Option<bool>,
+    max_nodes_before_yield: Option<u32>,
+    max_node_threshold: Option<(u32, u32)>,
+}
+
+impl PreDeviceConfig {
+    /// Creates a new empty [PreDeviceConfig].
+    pub fn new() -> PreDeviceConfig {
+        PreDeviceConfig::default()
+    }
+
+    /// Sets the global replication id.
+    pub fn global_rid(mut self, rid: u32) -> PreDeviceConfig {
+        self.global_rid = Some(rid);
+        self
+    }
+
+    /// Sets whether packets to a port are replicated to its backup port if the port is down.
+    pub fn port_protection(mut self, enable: bool) -> PreDeviceConfig {
+        self.port_protection = Some(enable);
+        self
+    }
+
+    /// Sets whether the PRE fails over to backup ports without involvement of the controller.
+    pub fn fast_failover(mut self, enable: bool) -> PreDeviceConfig {
+        self.fast_failover = Some(enable);
+        self
+    }
+
+    /// Sets the number of nodes the PRE processes before it yields to other replications.
+    pub fn max_nodes_before_yield(mut self, count: u32) -> PreDeviceConfig {
+        self.max_nodes_before_yield = Some(count);
+        self
+    }
+
+    /// Sets the maximum number of nodes and port LAG nodes of a replication.
+    pub fn max_node_threshold(mut self, node_count: u32, port_lag_count: u32) -> PreDeviceConfig {
+        self.max_node_threshold = Some((node_count, port_lag_count));
+        self
+    }
+
+    /// Returns the global replication id.
+    pub fn get_global_rid(&self) -> Option<u32> {
+        self.global_rid
+    }
+
+    /// Returns whether port protection is enabled.
+    pub fn get_port_protection(&self) -> Option<bool> {
+        self.port_protection
+    }
+
+    /// Returns whether fast failover is enabled.
+    pub fn get_fast_failover(&self) -> Option<bool> {
+        self.fast_failover
+    }
+
+    /// Returns the number of nodes the PRE processes before it yields.
+    pub fn get_max_nodes_before_yield(&self) -> Option<u32> {
+        self.max_nodes_before_yield
+    }
+
+    /// Returns the maximum number of nodes and port LAG nodes of a replication.
+    pub fn get_max_node_threshold(&self) -> Option<(u32, u32)> {
+        self.max_node_threshold
+    }
+
+    pub(crate) fn to_proto(self) -> bfrt_proto::PreDeviceConfig {
+        bfrt_proto::PreDeviceConfig {
+            pre_global_rid: self
+                .global_rid
+                .map(|global_rid| bfrt_proto::PreGlobalRid { global_rid }),
+            pre_port_protection: self
+                .port_protection
+                .map(|enable| bfrt_proto::PrePortProtection { enable }),
+            pre_fast_failover: self
+                .fast_failover
+                .map(|enable| bfrt_proto::PreFastFailover { enable }),
+            pre_max_nodes_before_yield: self
+                .max_nodes_before_yield
+                .map(|count| bfrt_proto::PreMaxNodesBeforeYield { count }),
+            pre_max_node_threshold: self.max_node_threshold.map(|(node_count, port_lag_count)| {
+                bfrt_proto::PreMaxNodeThreshold {
+                    node_count,
+                    port_lag_count,
+                }
+            }),
+        }
+    }
+
+    pub(crate) fn from_proto(config: &bfrt_proto::PreDeviceConfig) -> PreDeviceConfig {
+        PreDeviceConfig {
+            global_rid: config.pre_global_rid.map(|r| r.global_rid),
+            port_protection: config.pre_port_protection.map(|p| p.enable),
+            fast_failover: config.pre_fast_failover.map(|f| f.enable),
+            max_nodes_before_yield: config.pre_max_nodes_before_yield.map(|m| m.count),
+            max_node_threshold: config
+                .pre_max_node_threshold
+                .map(|t| (t.node_count, t.port_lag_count)),
+        }
+    }
+}
@@ -18,10 +18,17 @@
  */
 
 use crate::error::RBFRTError;
+use crate::selector::{ACTION_MEMBER_ID, SELECTOR_GROUP_ID};
 use crate::table::action_data::ActionDataRepeated;
-use crate::table::{ActionData, MatchValue, ToBytes};
+use crate::table::{ActionData, Direction, EntryHitState, MatchValue, ToBytes};
+use prost::Message;
 use std::collections::HashMap;
 
+/// Name of the data field containing the TTL of an entry.
+const ENTRY_TTL: &str = "$ENTRY_TTL";
+/// Name of the data field containing the hit state of an entry.
+const ENTRY_HIT_STATE: &str = "$ENTRY_HIT_STATE";
+
 /// Represents a table entry.
 #[derive(Debug)]
 pub struct TableEntry {
@@ -33,10 +40,12 @@ pub struct TableEntry {
     pub match_keys: HashMap<String, MatchValue>,
     /// Flag indicating if this is the default entry for the table.
     pub default_entry: bool,
-    /// Name of the associated action
+    /// Name of the associated action, empty if only the key was read.
     pub action: String,
-    /// Action data of the action, empty if not parameters are provided.
+    /// Action data of the action, empty if not parameters are provided or only the key was read.
     pub action_data: Vec<ActionData>,
+    /// Handle of the entry, only present if the switch addressed the entry by its handle.
+    pub handle: Option<u32>,
 }
 
 impl TableEntry {
@@ -79,15 +88,63 @@ impl TableEntry {
     pub fn get_action_name(&self) -> &str {
         &self.action
     }
+
+    /// Returns the TTL in milliseconds of an entry of an idle table, if it was read.
+    pub fn get_entry_ttl(&self) -> Option<u32> {
+        self.get_action_data(ENTRY_TTL).ok().map(|d| d.as_u32())
+    }
+
+    /// Returns the [EntryHitState] of an entry of an idle table in poll mode, if it was read.
+    ///
+    /// The hit state is only refreshed after [UpdateHitState](TableOperation::UpdateHitState) is executed on the table.
+    pub fn get_entry_hit_state(&self) -> Option<EntryHitState> {
+        let data = self.get_action_data(ENTRY_HIT_STATE).ok()?;
+
+        match String::decode(data.get_data().as_slice()).ok()?.as_str() {
+            "ENTRY_IDLE" => Some(EntryHitState::Idle),
+            "ENTRY_ACTIVE" => Some(EntryHitState::Active),
+            _ => None,
+        }
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) enum RequestType {
     Read,
     Write,
     Update,
+    Upsert,
+    ModifyIncAdd,
+    ModifyIncDelete,
     Operation,
     Delete,
+    Reset,
+}
+
+/// Represents the atomicity of a batch of write requests.
+///
+/// See the [Open-Tofino protobuf definition](https://github.com/barefootnetworks/Open-Tofino/blob/master/share/bf_rt_shared/proto/bfruntime.proto) for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Atomicity {
+    /// Each request of the batch is attempted, even if other requests fail.
+    #[default]
+    ContinueOnError,
+    /// The batch is rolled back if a single request fails.
+    /// The dataplane may observe intermediate states while the batch is applied.
+    RollbackOnError,
+    /// The batch is applied as a single transaction.
+    /// The dataplane either observes the state before or after the batch.
+    DataplaneAtomic,
+}
+
+impl Atomicity {
+    pub(crate) fn to_proto(self) -> i32 {
+        match self {
+            Atomicity::ContinueOnError => 0,
+            Atomicity::RollbackOnError => 1,
+            Atomicity::DataplaneAtomic => 2,
+        }
+    }
 }
 
 /// Represents all possible table operations.
@@ -96,6 +153,7 @@ pub enum TableOperation {
     None,
     SyncCounters,
     SyncRegister,
+    UpdateHitState,
 }
 
 impl TableOperation {
@@ -104,6 +162,7 @@ impl TableOperation {
             TableOperation::None => String::from(""),
             TableOperation::SyncCounters => String::from("SyncCounters"),
             TableOperation::SyncRegister => String::from("SyncRegisters"),
+            TableOperation::UpdateHitState => String::from("UpdateHitState"),
         }
     }
 }
@@ -138,6 +197,11 @@ pub struct Request {
     operation: TableOperation,
     is_default_entry: bool,
     pipe: Option<u32>,
+    from_hw: bool,
+    key_only: bool,
+    handle: Option<u32>,
+    direction: Option<Direction>,
+    parser: Option<u32>,
 }
 
 #[allow(dead_code)]
@@ -156,6 +220,11 @@ impl Request {
             operation: TableOperation::None,
             is_default_entry: false,
             pipe: None,
+            from_hw: false,
+            key_only: false,
+            handle: None,
+            direction: None,
+            parser: None,
         }
     }
 
@@ -197,7 +266,24 @@ impl Request {
         self.action.is_some()
     }
 
+    /// Addresses the entry by its `handle` instead of its match keys.
+    ///
+    /// Match keys are ignored if a handle is set.
+    /// See [get_entry_handle](crate::SwitchConnection::get_entry_handle) to retrieve the handle of an entry.
+    pub fn handle(mut self, handle: u32) -> Request {
+        self.handle = Some(handle);
+        self
+    }
+
+    /// Returns the handle of the entry, if set.
+    pub fn get_handle(&self) -> Option<u32> {
+        self.handle
+    }
+
     /// Sets the pipe the [Request] is for.
+    ///
+    /// Only tables with an asymmetric pipe scope can be addressed to a single pipe, see [set_entry_scope](crate::SwitchConnection::set_entry_scope).
+    /// Requests for other tables fail with [SymmetricTable](crate::error::RBFRTError::SymmetricTable).
     pub fn pipe(mut self, pipe: u32) -> Request {
         self.pipe = Some(pipe);
         self
@@ -208,7 +294,35 @@ impl Request {
         self.pipe
     }
 
+    /// Sets the [Direction] the [Request] is for.
+    ///
+    /// Overrides the default direction of the [SwitchConnection](crate::SwitchConnection), see [default_direction](crate::SwitchConnectionBuilder::default_direction).
+    pub fn direction(mut self, direction: Direction) -> Request {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Returns the [Request]'s [Direction], if set.
+    pub fn get_direction(&self) -> Option<Direction> {
+        self.direction
+    }
+
+    /// Sets the parser the [Request] is for, e.g., for parser value sets.
+    ///
+    /// Overrides the default parser of the [SwitchConnection](crate::SwitchConnection), see [default_parser](crate::SwitchConnectionBuilder::default_parser).
+    pub fn parser(mut self, parser: u32) -> Request {
+        self.parser = Some(parser);
+        self
+    }
+
+    /// Returns the [Request]'s parser, if set.
+    pub fn get_parser(&self) -> Option<u32> {
+        self.parser
+    }
+
     /// Sets if the [TableEntry] specified by this [Request] has to be used as the default entry in the switch.
+    ///
+    /// See [get_default_entry](crate::SwitchConnection::get_default_entry) and [set_default_entry](crate::SwitchConnection::set_default_entry) to read and write the default entry.
     pub fn default(mut self, is_default: bool) -> Request {
         self.is_default_entry = is_default;
         self
@@ -219,12 +333,54 @@ impl Request {
         self.is_default_entry
     }
 
+    /// Sets if the entries are read from the hardware instead of the software shadow of the switch.
+    ///
+    /// Only used for read [Requests](Request), e.g., to get up-to-date values of direct counters or registers.
+    pub fn from_hw(mut self, from_hw: bool) -> Request {
+        self.from_hw = from_hw;
+        self
+    }
+
+    /// Returns if the entries are read from the hardware.
+    pub fn is_from_hw(&self) -> bool {
+        self.from_hw
+    }
+
+    /// Sets if only the keys of the entries are read.
+    ///
+    /// Only used for read [Requests](Request).
+    /// The [TableEntries](TableEntry) of the response contain no action and no action data.
+    pub fn key_only(mut self, key_only: bool) -> Request {
+        self.key_only = key_only;
+        self
+    }
+
+    /// Returns if only the keys of the entries are read.
+    pub fn is_key_only(&self) -> bool {
+        self.key_only
+    }
+
     /// Adds the associated [ActionData].
     pub fn action_data<T: ToBytes>(mut self, name: &str, data: T) -> Request {
         self.action_data.push(ActionData::new(name, data));
         self
     }
 
+    /// Points the entry of an indirect match-action table to the action profile member `member_id`.
+    pub fn action_member(self, member_id: u32) -> Request {
+        self.action_data(ACTION_MEMBER_ID, member_id)
+    }
+
+    /// Points the entry of a match-action table with an action selector to the selector group `group_id`.
+    pub fn selector_group(self, group_id: u32) -> Request {
+        self.action_data(SELECTOR_GROUP_ID, group_id)
+    }
+
+    /// Sets the TTL in milliseconds of an entry of an idle table in [Notify](crate::table::IdleTableMode::Notify) mode.
+    pub fn entry_ttl(self, ttl_ms: u32) -> Request {
+        self.action_data(ENTRY_TTL, ttl_ms)
+    }
+
     /// Returns the associated [ActionData].
     pub fn get_action_data(&self) -> &Vec<ActionData> {
         &self.action_data
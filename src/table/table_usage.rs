@@ -0,0 +1,66 @@
+/* Copyright 2023-present University of Tuebingen, Chair of Communication Networks
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/*
+ * Steffen Lindner (steffen.lindner@uni-tuebingen.de)
+ */
+
+/// Represents the current occupancy of a table.
+#[derive(Debug, Clone)]
+pub struct TableUsage {
+    /// Id of the table.
+    pub table_id: u32,
+    /// Name of the table.
+    pub table_name: String,
+    /// Pipe of the occupancy, `None` if it covers all pipes.
+    pub pipe: Option<u32>,
+    /// Number of entries in the table.
+    pub usage: u32,
+    /// Declared size of the table in the P4 program.
+    pub size: u32,
+}
+
+impl TableUsage {
+    /// Returns the number of free entries.
+    pub fn free(&self) -> u32 {
+        self.size.saturating_sub(self.usage)
+    }
+
+    /// Returns the ratio of used entries to the declared size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rbfrt::table::TableUsage;
+    ///
+    /// let usage = TableUsage {
+    ///     table_id: 1,
+    ///     table_name: "ingress.ipv4_lpm".to_owned(),
+    ///     pipe: None,
+    ///     usage: 768,
+    ///     size: 1024,
+    /// };
+    ///
+    /// assert_eq!(usage.utilization(), 0.75);
+    /// assert_eq!(usage.free(), 256);
+    /// ```
+    pub fn utilization(&self) -> f64 {
+        if self.size == 0 {
+            0f64
+        } else {
+            self.usage as f64 / self.size as f64
+        }
+    }
+}
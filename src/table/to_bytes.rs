@@ -19,6 +19,7 @@
 
 use crate::bfrt::Convert;
 use crate::error::RBFRTError;
+use crate::error::RBFRTError::ConvertError;
 use std::array::TryFromSliceError;
 use std::net::{Ipv4Addr, Ipv6Addr};
 
@@ -41,6 +42,9 @@ pub trait ToBytes {
     fn to_bool(&self) -> bool {
         unimplemented!("Conversion not implemented.");
     }
+    fn to_f32(&self) -> Result<f32, RBFRTError> {
+        unimplemented!("Conversion not implemented.");
+    }
     fn to_ipv4(&self) -> Result<Ipv4Addr, RBFRTError> {
         unimplemented!("Conversion not implemented.");
     }
@@ -100,6 +104,12 @@ impl ToBytes for i32 {
     }
 }
 
+impl ToBytes for f32 {
+    fn to_bytes(&self) -> Vec<u8> {
+        f32::to_be_bytes(*self).to_vec()
+    }
+}
+
 impl ToBytes for bool {
     fn to_bytes(&self) -> Vec<u8> {
         if *self {
@@ -138,6 +148,33 @@ impl ToBytes for Vec<u8> {
         self.iter().any(|&x| x > 0u8)
     }
 
+    /// Converts the big-endian representation of a `f32` back into a `f32`.
+    ///
+    /// # Errors
+    ///
+    /// Throws an `RBFRTError` if the vector is longer than 4 bytes.
+    ///
+    /// # Example
+    ///
+    ///```
+    /// use rbfrt::table::ToBytes;
+    ///
+    /// assert_eq!(0.25f32.to_bytes().to_f32().unwrap(), 0.25);
+    /// assert!(vec![1u8, 2, 3, 4, 5].to_f32().is_err());
+    ///```
+    fn to_f32(&self) -> Result<f32, RBFRTError> {
+        let data = self.clone().convert("to_f32 call", 32)?;
+
+        match data.try_into() {
+            Ok(bytes) => Ok(f32::from_be_bytes(bytes)),
+            Err(value) => Err(ConvertError {
+                value,
+                name: "to_f32 call".to_owned(),
+                width: 32,
+            }),
+        }
+    }
+
     /// Converts `Vec<u8>` of length 4 to `Ipv4Add`.
     ///
     /// # Errors
@@ -0,0 +1,140 @@
+/* Copyright 2023-present University of Tuebingen, Chair of Communication Networks
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/*
+ * Steffen Lindner (steffen.lindner@uni-tuebingen.de)
+ */
+
+use crate::table::{Atomicity, Request, RequestType};
+
+/// Represents a batch of [Requests](Request) of different types that is committed in a single write request.
+///
+/// The requests are applied in the order they are added.
+///
+/// # Example
+///
+/// ```no_run
+/// use rbfrt::SwitchConnection;
+/// use rbfrt::table::{Atomicity, MatchValue, Request, Transaction};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let switch = SwitchConnection::builder("localhost", 50052)
+///         .device_id(0)
+///         .client_id(1)
+///         .p4_name("my_p4_program")
+///         .connect()
+///         .await?;
+///
+///     // move a route from port 10 to port 11
+///     let transaction = Transaction::new()
+///         .delete(
+///             Request::new("ingress.exact_forward")
+///                 .match_key("ig_intr_md.ingress_port", MatchValue::exact(10)),
+///         )
+///         .insert(
+///             Request::new("ingress.exact_forward")
+///                 .match_key("ig_intr_md.ingress_port", MatchValue::exact(11))
+///                 .action("ingress.do_forward")
+///                 .action_data("e_port", 20),
+///         )
+///         .atomicity(Atomicity::DataplaneAtomic);
+///
+///     switch.commit_transaction(transaction).await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Transaction {
+    requests: Vec<Request>,
+    atomicity: Atomicity,
+}
+
+impl Transaction {
+    /// Creates a new empty [Transaction] with [Atomicity::ContinueOnError].
+    pub fn new() -> Transaction {
+        Default::default()
+    }
+
+    /// Adds a [Request] that inserts a new entry.
+    ///
+    /// See [write_table_entry](crate::SwitchConnection::write_table_entry).
+    pub fn insert(mut self, request: Request) -> Transaction {
+        self.requests.push(request.request_type(RequestType::Write));
+        self
+    }
+
+    /// Adds a [Request] that modifies an existing entry.
+    ///
+    /// See [update_table_entry](crate::SwitchConnection::update_table_entry).
+    pub fn modify(mut self, request: Request) -> Transaction {
+        self.requests
+            .push(request.request_type(RequestType::Update));
+        self
+    }
+
+    /// Adds a [Request] that inserts or modifies an entry.
+    ///
+    /// See [upsert_table_entry](crate::SwitchConnection::upsert_table_entry).
+    pub fn upsert(mut self, request: Request) -> Transaction {
+        self.requests
+            .push(request.request_type(RequestType::Upsert));
+        self
+    }
+
+    /// Adds a [Request] that deletes an entry.
+    ///
+    /// See [delete_table_entry](crate::SwitchConnection::delete_table_entry).
+    pub fn delete(mut self, request: Request) -> Transaction {
+        self.requests
+            .push(request.request_type(RequestType::Delete));
+        self
+    }
+
+    /// Adds a [Request] that executes a [TableOperation](crate::table::TableOperation).
+    ///
+    /// See [execute_operation](crate::SwitchConnection::execute_operation).
+    pub fn operation(mut self, request: Request) -> Transaction {
+        self.requests
+            .push(request.request_type(RequestType::Operation));
+        self
+    }
+
+    /// Sets the [Atomicity] of the [Transaction].
+    pub fn atomicity(self, atomicity: Atomicity) -> Transaction {
+        Transaction { atomicity, ..self }
+    }
+
+    /// Returns the [Atomicity] of the [Transaction].
+    pub fn get_atomicity(&self) -> Atomicity {
+        self.atomicity
+    }
+
+    /// Returns all [Requests](Request) of the [Transaction] in order.
+    pub fn get_requests(&self) -> &Vec<Request> {
+        &self.requests
+    }
+
+    /// Returns the number of [Requests](Request) in the [Transaction].
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    /// Returns if the [Transaction] contains no [Requests](Request).
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+}
@@ -0,0 +1,61 @@
+/* Copyright 2023-present University of Tuebingen, Chair of Communication Networks
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/*
+ * Steffen Lindner (steffen.lindner@uni-tuebingen.de)
+ */
+
+use crate::table::MatchValue;
+use std::collections::HashMap;
+
+/// Represents an idle timeout notification sent from the switch to the controller.
+///
+/// Notifications are only sent for idle tables in [Notify](crate::table::IdleTableMode::Notify) mode.
+/// See [enable_idle_table](crate::SwitchConnection::enable_idle_table).
+#[derive(Debug, Clone)]
+pub enum IdleTimeoutEvent {
+    /// The entry was not hit within its TTL.
+    EntryIdle {
+        /// Name of the table.
+        table_name: String,
+        /// Names and their values of the match keys of the entry.
+        match_keys: HashMap<String, MatchValue>,
+    },
+    /// The entry was hit again after it became idle.
+    EntryActive {
+        /// Name of the table.
+        table_name: String,
+        /// Names and their values of the match keys of the entry.
+        match_keys: HashMap<String, MatchValue>,
+    },
+}
+
+impl IdleTimeoutEvent {
+    /// Returns the name of the table of the entry.
+    pub fn get_table_name(&self) -> &str {
+        match self {
+            IdleTimeoutEvent::EntryIdle { table_name, .. }
+            | IdleTimeoutEvent::EntryActive { table_name, .. } => table_name,
+        }
+    }
+
+    /// Returns the match keys of the entry.
+    pub fn get_match_keys(&self) -> &HashMap<String, MatchValue> {
+        match self {
+            IdleTimeoutEvent::EntryIdle { match_keys, .. }
+            | IdleTimeoutEvent::EntryActive { match_keys, .. } => match_keys,
+        }
+    }
+}
@@ -17,9 +17,15 @@
  * Steffen Lindner (steffen.lindner@uni-tuebingen.de)
  */
 mod digest;
+mod idle_timeout;
+mod multicast_manager;
 mod port_manager;
 mod pretty_printer;
 
 pub use digest::Digest;
+pub use idle_timeout::IdleTimeoutEvent;
+pub use multicast_manager::{
+    MulticastGroup, MulticastGroupMember, MulticastLag, MulticastManager, MulticastNode,
+};
 pub use port_manager::{AutoNegotiation, Loopback, Port, PortManager, Speed, FEC};
 pub use pretty_printer::PrettyPrinter;
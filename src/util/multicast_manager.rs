@@ -0,0 +1,725 @@
+/* Copyright 2023-present University of Tuebingen, Chair of Communication Networks
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/*
+ * Steffen Lindner (steffen.lindner@uni-tuebingen.de)
+ */
+
+use crate::error::RBFRTError;
+use crate::error::RBFRTError::{EntryNotFound, MulticastIdInUse, UnknownMulticastNode};
+use crate::table::{Atomicity, MatchValue, TableEntry, ToBytes, Transaction};
+use crate::util::PortManager;
+use crate::{table, SwitchConnection};
+use std::collections::HashMap;
+
+const PRE_MGID: &str = "$pre.mgid";
+const PRE_NODE: &str = "$pre.node";
+const PRE_LAG: &str = "$pre.lag";
+const PRE_PRUNE: &str = "$pre.prune";
+
+const MGID: &str = "$MGID";
+const MULTICAST_NODE_ID: &str = "$MULTICAST_NODE_ID";
+const MULTICAST_NODE_L1_XID_VALID: &str = "$MULTICAST_NODE_L1_XID_VALID";
+const MULTICAST_NODE_L1_XID: &str = "$MULTICAST_NODE_L1_XID";
+const MULTICAST_RID: &str = "$MULTICAST_RID";
+const MULTICAST_LAG_ID: &str = "$MULTICAST_LAG_ID";
+const MULTICAST_L2_XID: &str = "$MULTICAST_L2_XID";
+const DEV_PORT: &str = "$DEV_PORT";
+
+/// Represents a node of the packet replication engine.
+///
+/// A node replicates a packet to its ports and LAGs, and tags each copy with its replication id (RID).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MulticastNode {
+    id: u32,
+    rid: u16,
+    ports: Vec<(u32, u8)>,
+    lags: Vec<u8>,
+}
+
+impl MulticastNode {
+    /// Creates a new [MulticastNode] with the given node `id` and replication id `rid`.
+    pub fn new(id: u32, rid: u16) -> MulticastNode {
+        MulticastNode {
+            id,
+            rid,
+            ports: vec![],
+            lags: vec![],
+        }
+    }
+
+    /// Adds the frontpanel `port` and `channel` to the node.
+    pub fn port(mut self, port: u32, channel: u8) -> MulticastNode {
+        self.ports.push((port, channel));
+        self
+    }
+
+    /// Adds the LAG with the given `lag_id` to the node.
+    pub fn lag(mut self, lag_id: u8) -> MulticastNode {
+        self.lags.push(lag_id);
+        self
+    }
+
+    /// Returns the `id` of the node.
+    pub fn get_id(&self) -> u32 {
+        self.id
+    }
+
+    /// Returns the replication id of the node.
+    pub fn get_rid(&self) -> u16 {
+        self.rid
+    }
+
+    /// Returns the frontpanel ports `(port, channel)` of the node.
+    pub fn get_ports(&self) -> &Vec<(u32, u8)> {
+        &self.ports
+    }
+
+    /// Returns the ids of the LAGs of the node.
+    pub fn get_lags(&self) -> &Vec<u8> {
+        &self.lags
+    }
+}
+
+/// Represents a [MulticastNode] as member of a [MulticastGroup].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MulticastGroupMember {
+    /// Node of the member.
+    pub node: MulticastNode,
+    /// Level 1 exclusion id of the member.
+    ///
+    /// Copies of the node are suppressed if the packet carries the same level 1 exclusion id.
+    pub l1_xid: Option<u16>,
+}
+
+/// Represents a multicast group of the packet replication engine.
+///
+/// # Example
+///
+/// ```
+/// use rbfrt::util::{MulticastGroup, MulticastNode};
+///
+/// let group = MulticastGroup::new(1)
+///     .node(MulticastNode::new(1, 0).port(1, 0).port(2, 0))
+///     .node_with_l1_xid(MulticastNode::new(2, 1).port(3, 0), 5);
+///
+/// assert_eq!(group.get_members().len(), 2);
+/// assert_eq!(group.get_ports(), vec![(1, 0), (2, 0), (3, 0)]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MulticastGroup {
+    mgid: u16,
+    members: Vec<MulticastGroupMember>,
+}
+
+impl MulticastGroup {
+    /// Creates a new empty [MulticastGroup] with the multicast group id `mgid`.
+    pub fn new(mgid: u16) -> MulticastGroup {
+        MulticastGroup {
+            mgid,
+            members: vec![],
+        }
+    }
+
+    /// Adds a [MulticastNode] to the group.
+    pub fn node(mut self, node: MulticastNode) -> MulticastGroup {
+        self.members
+            .push(MulticastGroupMember { node, l1_xid: None });
+        self
+    }
+
+    /// Adds a [MulticastNode] with the level 1 exclusion id `l1_xid` to the group.
+    pub fn node_with_l1_xid(mut self, node: MulticastNode, l1_xid: u16) -> MulticastGroup {
+        self.members.push(MulticastGroupMember {
+            node,
+            l1_xid: Some(l1_xid),
+        });
+        self
+    }
+
+    /// Returns the multicast group id.
+    pub fn get_mgid(&self) -> u16 {
+        self.mgid
+    }
+
+    /// Returns the members of the group.
+    pub fn get_members(&self) -> &Vec<MulticastGroupMember> {
+        &self.members
+    }
+
+    /// Returns the frontpanel ports `(port, channel)` of all nodes of the group.
+    pub fn get_ports(&self) -> Vec<(u32, u8)> {
+        self.members
+            .iter()
+            .flat_map(|m| m.node.ports.iter().copied())
+            .collect()
+    }
+}
+
+/// Represents a LAG of the packet replication engine.
+///
+/// A node replicates a packet only once to a LAG.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MulticastLag {
+    id: u8,
+    ports: Vec<(u32, u8)>,
+}
+
+impl MulticastLag {
+    /// Creates a new empty [MulticastLag] with the given `id`.
+    pub fn new(id: u8) -> MulticastLag {
+        MulticastLag { id, ports: vec![] }
+    }
+
+    /// Adds the frontpanel `port` and `channel` to the LAG.
+    pub fn port(mut self, port: u32, channel: u8) -> MulticastLag {
+        self.ports.push((port, channel));
+        self
+    }
+
+    /// Returns the `id` of the LAG.
+    pub fn get_id(&self) -> u8 {
+        self.id
+    }
+
+    /// Returns the frontpanel ports `(port, channel)` of the LAG.
+    pub fn get_ports(&self) -> &Vec<(u32, u8)> {
+        &self.ports
+    }
+}
+
+/// Manager to add, delete, ... multicast groups, nodes, LAGs and exclusion ids of the packet replication engine (PRE) of the connected switch.
+///
+/// Ports are given as frontpanel ports and mapped to `dev_ports` like in the [PortManager].
+///
+/// # Example
+///
+/// ```no_run
+/// use rbfrt::SwitchConnection;
+/// use rbfrt::util::{MulticastGroup, MulticastManager, MulticastNode};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let switch = SwitchConnection::builder("localhost", 50052)
+///         .device_id(0)
+///         .client_id(1)
+///         .p4_name("my_p4_program")
+///         .connect()
+///         .await?;
+///
+///     let mm = MulticastManager::new(&switch).await;
+///
+///     let group = MulticastGroup::new(1).node(MulticastNode::new(1, 0).port(1, 0).port(2, 0));
+///
+///     mm.add_group(&switch, &group).await?;
+///
+///     let group = mm.get_group(&switch, 1).await?;
+///
+///     assert_eq!(group.get_ports(), vec![(1, 0), (2, 0)]);
+///
+///     Ok(())
+/// }
+/// ```
+pub struct MulticastManager {
+    port_manager: PortManager,
+}
+
+impl MulticastManager {
+    /// Creates a new [MulticastManager] and reads the mapping of frontpanel ports to `dev_ports` from the `switch`.
+    pub async fn new(switch: &SwitchConnection) -> MulticastManager {
+        MulticastManager {
+            port_manager: PortManager::new(switch).await,
+        }
+    }
+
+    /// Adds the [MulticastGroup] together with its [MulticastNodes](MulticastNode).
+    ///
+    /// Nodes that already exist with the same configuration, e.g., because they are shared with another group, are reused.
+    /// Returns [MulticastIdInUse](RBFRTError::MulticastIdInUse) if a node id is already used by a different node.
+    /// The new nodes and the group are committed in a single [Transaction] that is rolled back on errors.
+    pub async fn add_group(
+        &self,
+        switch: &SwitchConnection,
+        group: &MulticastGroup,
+    ) -> Result<(), RBFRTError> {
+        let existing = self.read_nodes(switch, None).await?;
+        let mut transaction = Transaction::new().atomicity(Atomicity::RollbackOnError);
+
+        for node in group.members.iter().map(|m| &m.node) {
+            match existing.get(&node.id) {
+                Some(e) if same_node(e, node) => {}
+                Some(_) => {
+                    return Err(MulticastIdInUse {
+                        kind: "node".to_owned(),
+                        id: node.id,
+                    })
+                }
+                // nodes have to exist before they are referenced by the group
+                None => transaction = transaction.insert(self.node_request(node)?),
+            }
+        }
+
+        transaction = transaction.insert(self.group_request(group));
+
+        switch.commit_transaction(transaction).await
+    }
+
+    /// Replaces the members of an existing [MulticastGroup].
+    ///
+    /// Nodes of the group have to exist, see [add_node](MulticastManager::add_node).
+    pub async fn update_group(
+        &self,
+        switch: &SwitchConnection,
+        group: &MulticastGroup,
+    ) -> Result<(), RBFRTError> {
+        switch.update_table_entry(self.group_request(group)).await?;
+
+        Ok(())
+    }
+
+    /// Deletes the multicast group with the given `mgid` together with its nodes.
+    ///
+    /// Nodes that are also members of other multicast groups are kept.
+    pub async fn delete_group(
+        &self,
+        switch: &SwitchConnection,
+        mgid: u16,
+    ) -> Result<(), RBFRTError> {
+        let mut node_ids = vec![];
+        let mut shared = vec![];
+
+        for entry in &switch
+            .get_table_entries(table::Request::new(PRE_MGID))
+            .await?
+        {
+            let ids = int_arr(entry, MULTICAST_NODE_ID);
+
+            if entry.get_key(MGID)?.get_exact_value().to_u32() == mgid as u32 {
+                node_ids = ids;
+            } else {
+                shared.extend(ids);
+            }
+        }
+
+        // the group references its nodes and has to be deleted first
+        let req = table::Request::new(PRE_MGID).match_key(MGID, MatchValue::exact(mgid));
+        switch.delete_table_entry(req).await?;
+
+        let node_req: Vec<table::Request> = node_ids
+            .iter()
+            .filter(|id| !shared.contains(id))
+            .map(|id| {
+                table::Request::new(PRE_NODE).match_key(MULTICAST_NODE_ID, MatchValue::exact(*id))
+            })
+            .collect();
+
+        if !node_req.is_empty() {
+            switch.delete_table_entries(node_req).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the [MulticastGroup] with the given `mgid` including the ports and LAGs of its nodes.
+    pub async fn get_group(
+        &self,
+        switch: &SwitchConnection,
+        mgid: u16,
+    ) -> Result<MulticastGroup, RBFRTError> {
+        let req = table::Request::new(PRE_MGID).match_key(MGID, MatchValue::exact(mgid));
+        let entries = switch.get_table_entries(req).await?;
+
+        let Some(entry) = entries.first() else {
+            return Err(RBFRTError::UnknownReadResult {});
+        };
+
+        let node_ids = int_arr(entry, MULTICAST_NODE_ID);
+        let nodes = self.read_nodes(switch, Some(&node_ids)).await?;
+
+        self.parse_group(entry, &nodes)
+    }
+
+    /// Returns all [MulticastGroups](MulticastGroup).
+    pub async fn get_groups(
+        &self,
+        switch: &SwitchConnection,
+    ) -> Result<Vec<MulticastGroup>, RBFRTError> {
+        let entries = switch
+            .get_table_entries(table::Request::new(PRE_MGID))
+            .await?;
+        let nodes = self.read_nodes(switch, None).await?;
+
+        entries
+            .iter()
+            .map(|entry| self.parse_group(entry, &nodes))
+            .collect()
+    }
+
+    /// Adds the [MulticastNode].
+    pub async fn add_node(
+        &self,
+        switch: &SwitchConnection,
+        node: &MulticastNode,
+    ) -> Result<(), RBFRTError> {
+        self.add_nodes(switch, std::slice::from_ref(node)).await
+    }
+
+    /// Adds all provided [MulticastNodes](MulticastNode).
+    pub async fn add_nodes(
+        &self,
+        switch: &SwitchConnection,
+        nodes: &[MulticastNode],
+    ) -> Result<(), RBFRTError> {
+        let all_requests: Result<Vec<table::Request>, RBFRTError> =
+            nodes.iter().map(|node| self.node_request(node)).collect();
+
+        switch.write_table_entries(all_requests?).await?;
+
+        Ok(())
+    }
+
+    /// Replaces the RID, ports and LAGs of an existing [MulticastNode].
+    pub async fn update_node(
+        &self,
+        switch: &SwitchConnection,
+        node: &MulticastNode,
+    ) -> Result<(), RBFRTError> {
+        switch.update_table_entry(self.node_request(node)?).await?;
+
+        Ok(())
+    }
+
+    /// Deletes the [MulticastNode] with the given `node_id`.
+    ///
+    /// The node must not be a member of a multicast group.
+    pub async fn delete_node(
+        &self,
+        switch: &SwitchConnection,
+        node_id: u32,
+    ) -> Result<(), RBFRTError> {
+        let req =
+            table::Request::new(PRE_NODE).match_key(MULTICAST_NODE_ID, MatchValue::exact(node_id));
+
+        switch.delete_table_entry(req).await?;
+
+        Ok(())
+    }
+
+    /// Returns the [MulticastNode] with the given `node_id`.
+    pub async fn get_node(
+        &self,
+        switch: &SwitchConnection,
+        node_id: u32,
+    ) -> Result<MulticastNode, RBFRTError> {
+        let req =
+            table::Request::new(PRE_NODE).match_key(MULTICAST_NODE_ID, MatchValue::exact(node_id));
+
+        match switch.get_table_entries(req).await?.first() {
+            Some(entry) => self.parse_node(entry),
+            None => Err(RBFRTError::UnknownReadResult {}),
+        }
+    }
+
+    /// Adds the [MulticastLag].
+    ///
+    /// Returns [MulticastIdInUse](RBFRTError::MulticastIdInUse) if the LAG already has different ports,
+    /// see [delete_lag](MulticastManager::delete_lag).
+    pub async fn add_lag(
+        &self,
+        switch: &SwitchConnection,
+        lag: &MulticastLag,
+    ) -> Result<(), RBFRTError> {
+        let current = self
+            .read_ports(switch, PRE_LAG, MULTICAST_LAG_ID, lag.id as u32)
+            .await?;
+
+        if !current.is_empty() && !same_ports(&current, &lag.ports) {
+            return Err(MulticastIdInUse {
+                kind: "LAG".to_owned(),
+                id: lag.id as u32,
+            });
+        }
+
+        self.write_ports(switch, PRE_LAG, MULTICAST_LAG_ID, lag.id as u32, &lag.ports)
+            .await
+    }
+
+    /// Removes all ports from the LAG with the given `lag_id`.
+    pub async fn delete_lag(
+        &self,
+        switch: &SwitchConnection,
+        lag_id: u8,
+    ) -> Result<(), RBFRTError> {
+        self.write_ports(switch, PRE_LAG, MULTICAST_LAG_ID, lag_id as u32, &[])
+            .await
+    }
+
+    /// Returns the [MulticastLag] with the given `lag_id`.
+    pub async fn get_lag(
+        &self,
+        switch: &SwitchConnection,
+        lag_id: u8,
+    ) -> Result<MulticastLag, RBFRTError> {
+        let req =
+            table::Request::new(PRE_LAG).match_key(MULTICAST_LAG_ID, MatchValue::exact(lag_id));
+
+        match switch.get_table_entries(req).await?.first() {
+            Some(entry) => Ok(MulticastLag {
+                id: lag_id,
+                ports: self.frontpanel_ports(&int_arr(entry, DEV_PORT))?,
+            }),
+            None => Err(RBFRTError::UnknownReadResult {}),
+        }
+    }
+
+    /// Sets the frontpanel `ports` that are pruned for packets with the level 2 exclusion id `l2_xid`.
+    ///
+    /// Returns [MulticastIdInUse](RBFRTError::MulticastIdInUse) if the exclusion id already prunes different ports,
+    /// see [delete_l2_exclusion](MulticastManager::delete_l2_exclusion).
+    pub async fn add_l2_exclusion(
+        &self,
+        switch: &SwitchConnection,
+        l2_xid: u16,
+        ports: &[(u32, u8)],
+    ) -> Result<(), RBFRTError> {
+        let current = self
+            .read_ports(switch, PRE_PRUNE, MULTICAST_L2_XID, l2_xid as u32)
+            .await?;
+
+        if !current.is_empty() && !same_ports(&current, ports) {
+            return Err(MulticastIdInUse {
+                kind: "L2 exclusion id".to_owned(),
+                id: l2_xid as u32,
+            });
+        }
+
+        self.write_ports(switch, PRE_PRUNE, MULTICAST_L2_XID, l2_xid as u32, ports)
+            .await
+    }
+
+    /// Removes all pruned ports of the level 2 exclusion id `l2_xid`.
+    pub async fn delete_l2_exclusion(
+        &self,
+        switch: &SwitchConnection,
+        l2_xid: u16,
+    ) -> Result<(), RBFRTError> {
+        self.write_ports(switch, PRE_PRUNE, MULTICAST_L2_XID, l2_xid as u32, &[])
+            .await
+    }
+
+    /// Returns the frontpanel ports that are pruned for packets with the level 2 exclusion id `l2_xid`.
+    pub async fn get_l2_exclusion(
+        &self,
+        switch: &SwitchConnection,
+        l2_xid: u16,
+    ) -> Result<Vec<(u32, u8)>, RBFRTError> {
+        let req =
+            table::Request::new(PRE_PRUNE).match_key(MULTICAST_L2_XID, MatchValue::exact(l2_xid));
+
+        match switch.get_table_entries(req).await?.first() {
+            Some(entry) => self.frontpanel_ports(&int_arr(entry, DEV_PORT)),
+            None => Err(RBFRTError::UnknownReadResult {}),
+        }
+    }
+
+    /// Reads the frontpanel ports of the LAG or L2 exclusion entry with the given `id`, or no ports if the entry does not exist.
+    async fn read_ports(
+        &self,
+        switch: &SwitchConnection,
+        table_name: &str,
+        key: &str,
+        id: u32,
+    ) -> Result<Vec<(u32, u8)>, RBFRTError> {
+        let req = table::Request::new(table_name).match_key(key, MatchValue::exact(id));
+
+        match switch.get_table_entries(req).await {
+            Ok(entries) => match entries.first() {
+                Some(entry) => self.frontpanel_ports(&int_arr(entry, DEV_PORT)),
+                None => Ok(vec![]),
+            },
+            Err(EntryNotFound { .. }) => Ok(vec![]),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Sets the frontpanel `ports` of the LAG or L2 exclusion entry with the given `id`.
+    async fn write_ports(
+        &self,
+        switch: &SwitchConnection,
+        table_name: &str,
+        key: &str,
+        id: u32,
+        ports: &[(u32, u8)],
+    ) -> Result<(), RBFRTError> {
+        let req = table::Request::new(table_name)
+            .match_key(key, MatchValue::exact(id))
+            .action_data_repeated(DEV_PORT, self.dev_ports(ports)?);
+
+        switch.upsert_table_entry(req).await
+    }
+
+    fn group_request(&self, group: &MulticastGroup) -> table::Request {
+        table::Request::new(PRE_MGID)
+            .match_key(MGID, MatchValue::exact(group.mgid))
+            .action_data_repeated(
+                MULTICAST_NODE_ID,
+                group.members.iter().map(|m| m.node.id).collect(),
+            )
+            .action_data_repeated(
+                MULTICAST_NODE_L1_XID_VALID,
+                group.members.iter().map(|m| m.l1_xid.is_some()).collect(),
+            )
+            .action_data_repeated(
+                MULTICAST_NODE_L1_XID,
+                group
+                    .members
+                    .iter()
+                    .map(|m| m.l1_xid.unwrap_or_default())
+                    .collect(),
+            )
+    }
+
+    fn node_request(&self, node: &MulticastNode) -> Result<table::Request, RBFRTError> {
+        Ok(table::Request::new(PRE_NODE)
+            .match_key(MULTICAST_NODE_ID, MatchValue::exact(node.id))
+            .action_data(MULTICAST_RID, node.rid)
+            .action_data_repeated(MULTICAST_LAG_ID, node.lags.clone())
+            .action_data_repeated(DEV_PORT, self.dev_ports(&node.ports)?))
+    }
+
+    /// Reads the nodes with the given `node_ids`, or all nodes if no ids are given.
+    async fn read_nodes(
+        &self,
+        switch: &SwitchConnection,
+        node_ids: Option<&[u32]>,
+    ) -> Result<HashMap<u32, MulticastNode>, RBFRTError> {
+        let entries = match node_ids {
+            Some([]) => vec![],
+            Some(node_ids) => {
+                let req = node_ids
+                    .iter()
+                    .map(|id| {
+                        table::Request::new(PRE_NODE)
+                            .match_key(MULTICAST_NODE_ID, MatchValue::exact(*id))
+                    })
+                    .collect();
+
+                switch.get_tables_entries(req).await?
+            }
+            None => {
+                switch
+                    .get_table_entries(table::Request::new(PRE_NODE))
+                    .await?
+            }
+        };
+
+        let mut nodes = HashMap::new();
+
+        for entry in &entries {
+            let node = self.parse_node(entry)?;
+            nodes.insert(node.id, node);
+        }
+
+        Ok(nodes)
+    }
+
+    fn parse_group(
+        &self,
+        entry: &TableEntry,
+        nodes: &HashMap<u32, MulticastNode>,
+    ) -> Result<MulticastGroup, RBFRTError> {
+        let mgid = entry.get_key(MGID)?.get_exact_value().to_u32() as u16;
+        let node_ids = int_arr(entry, MULTICAST_NODE_ID);
+        let l1_xid_valid = entry
+            .get_action_data(MULTICAST_NODE_L1_XID_VALID)
+            .map(|d| d.get_data().clone())
+            .unwrap_or_default();
+        let l1_xids = int_arr(entry, MULTICAST_NODE_L1_XID);
+
+        let members = node_ids
+            .iter()
+            .enumerate()
+            .map(|(i, id)| {
+                let Some(node) = nodes.get(id) else {
+                    return Err(UnknownMulticastNode { node_id: *id, mgid });
+                };
+
+                Ok(MulticastGroupMember {
+                    node: node.clone(),
+                    l1_xid: match l1_xid_valid.get(i) {
+                        Some(valid) if *valid > 0 => l1_xids.get(i).map(|x| *x as u16),
+                        _ => None,
+                    },
+                })
+            })
+            .collect::<Result<Vec<MulticastGroupMember>, RBFRTError>>()?;
+
+        Ok(MulticastGroup { mgid, members })
+    }
+
+    fn parse_node(&self, entry: &TableEntry) -> Result<MulticastNode, RBFRTError> {
+        Ok(MulticastNode {
+            id: entry.get_key(MULTICAST_NODE_ID)?.get_exact_value().to_u32(),
+            rid: entry.get_action_data(MULTICAST_RID)?.as_u32() as u16,
+            ports: self.frontpanel_ports(&int_arr(entry, DEV_PORT))?,
+            lags: int_arr(entry, MULTICAST_LAG_ID)
+                .iter()
+                .map(|l| *l as u8)
+                .collect(),
+        })
+    }
+
+    fn dev_ports(&self, ports: &[(u32, u8)]) -> Result<Vec<u32>, RBFRTError> {
+        ports
+            .iter()
+            .map(|(port, channel)| self.port_manager.dev_port(*port, *channel))
+            .collect()
+    }
+
+    fn frontpanel_ports(&self, dev_ports: &[u32]) -> Result<Vec<(u32, u8)>, RBFRTError> {
+        dev_ports
+            .iter()
+            .map(|dev_port| self.port_manager.frontpanel_port(*dev_port))
+            .collect()
+    }
+}
+
+/// Returns the values of the repeated integer data field `name`, or an empty list if it is not present.
+fn int_arr(entry: &TableEntry, name: &str) -> Vec<u32> {
+    entry
+        .get_action_data(name)
+        .map(|d| d.get_data().to_int_arr())
+        .unwrap_or_default()
+}
+
+/// Returns whether the nodes `a` and `b` have the same configuration, regardless of the order of their ports and LAGs.
+fn same_node(a: &MulticastNode, b: &MulticastNode) -> bool {
+    let mut a_lags = a.lags.clone();
+    let mut b_lags = b.lags.clone();
+    a_lags.sort();
+    b_lags.sort();
+
+    a.rid == b.rid && a_lags == b_lags && same_ports(&a.ports, &b.ports)
+}
+
+/// Returns whether `a` and `b` contain the same ports, regardless of their order.
+fn same_ports(a: &[(u32, u8)], b: &[(u32, u8)]) -> bool {
+    let mut a = a.to_vec();
+    let mut b = b.to_vec();
+    a.sort();
+    b.sort();
+
+    a == b
+}
@@ -0,0 +1,268 @@
+/* Copyright 2023-present University of Tuebingen, Chair of Communication Networks
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/*
+ * Steffen Lindner (steffen.lindner@uni-tuebingen.de)
+ */
+
+use crate::error::RBFRTError;
+use crate::table::{ActionData, MatchValue, TableEntry, ToBytes};
+use prost::Message;
+use std::collections::HashMap;
+
+/// WRED index type.
+pub type IndexType = u32;
+
+pub(crate) const WRED_INDEX: &str = "$WRED_INDEX";
+pub(crate) const WRED_SPEC_TIME_CONSTANT_NS: &str = "$WRED_SPEC_TIME_CONSTANT_NS";
+pub(crate) const WRED_SPEC_MIN_THRESH_CELLS: &str = "$WRED_SPEC_MIN_THRESH_CELLS";
+pub(crate) const WRED_SPEC_MAX_THRESH_CELLS: &str = "$WRED_SPEC_MAX_THRESH_CELLS";
+pub(crate) const WRED_SPEC_MAX_PROBABILITY: &str = "$WRED_SPEC_MAX_PROBABILITY";
+
+/// Represents the configuration of a weighted random early detection (WRED) instance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WredSpec {
+    /// Time constant in nanoseconds of the averaged queue depth.
+    pub time_constant_ns: f32,
+    /// Queue depth in cells from which packets are dropped.
+    pub min_threshold_cells: u32,
+    /// Queue depth in cells from which all packets are dropped.
+    pub max_threshold_cells: u32,
+    /// Drop probability at the maximum threshold, between 0 and 1.
+    pub max_probability: f32,
+}
+
+impl WredSpec {
+    /// Parses the [WredSpec] from the data fields of a [TableEntry].
+    ///
+    /// Returns [InvalidWredSpec](RBFRTError::InvalidWredSpec) if a field of the spec is missing or malformed.
+    fn parse(entry: &TableEntry) -> Result<WredSpec, RBFRTError> {
+        Ok(WredSpec {
+            time_constant_ns: decode_float(entry, WRED_SPEC_TIME_CONSTANT_NS)?,
+            min_threshold_cells: field(entry, WRED_SPEC_MIN_THRESH_CELLS)?.as_u32(),
+            max_threshold_cells: field(entry, WRED_SPEC_MAX_THRESH_CELLS)?.as_u32(),
+            max_probability: decode_float(entry, WRED_SPEC_MAX_PROBABILITY)?,
+        })
+    }
+}
+
+/// Represents the configuration of a direct or indirect WRED.
+#[derive(Debug, Clone)]
+pub struct Wred {
+    /// name of the WRED or the match-action table of a direct WRED
+    name: String,
+    /// entries of the WRED
+    entries: Vec<WredEntry>,
+}
+
+impl Wred {
+    /// Returns the `name` of the WRED.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns all `entries` of the WRED.
+    pub fn entries(&self) -> &Vec<WredEntry> {
+        &self.entries
+    }
+
+    /// Returns the [WredEntry] at a specific `index` of an indirect WRED.
+    pub fn get(&self, index: IndexType) -> Option<&WredEntry> {
+        self.entries.iter().find(|e| e.index == Some(index))
+    }
+
+    /// Parses [TableEntries](TableEntry) into [WredEntries](WredEntry).
+    ///
+    /// Returns [InvalidWredSpec](RBFRTError::InvalidWredSpec) if an entry has no complete [WredSpec].
+    pub(crate) fn parse_wred_entries(
+        entries: Vec<TableEntry>,
+        name: &str,
+    ) -> Result<Wred, RBFRTError> {
+        let entries = entries
+            .into_iter()
+            .map(|e| {
+                let spec = WredSpec::parse(&e)?;
+                let mut match_keys = e.match_keys;
+
+                let index = match_keys
+                    .remove(WRED_INDEX)
+                    .map(|i| i.get_exact_value().to_u32());
+
+                Ok(WredEntry {
+                    index,
+                    match_keys,
+                    spec,
+                })
+            })
+            .collect::<Result<Vec<WredEntry>, RBFRTError>>()?;
+
+        Ok(Wred {
+            name: name.to_owned(),
+            entries,
+        })
+    }
+}
+
+/// Represents the configuration of a WRED at a specific index or of a direct WRED of a table entry.
+#[derive(Debug, Clone)]
+pub struct WredEntry {
+    index: Option<IndexType>,
+    match_keys: HashMap<String, MatchValue>,
+    spec: WredSpec,
+}
+
+impl WredEntry {
+    /// Returns the `index` of an indirect WRED entry.
+    pub fn get_index(&self) -> Option<IndexType> {
+        self.index
+    }
+
+    /// Returns the match keys of the table entry of a direct WRED.
+    pub fn get_match_keys(&self) -> &HashMap<String, MatchValue> {
+        &self.match_keys
+    }
+
+    /// Returns the [WredSpec] of the entry.
+    pub fn get_spec(&self) -> &WredSpec {
+        &self.spec
+    }
+}
+
+/// Represents a WRED request.
+///
+/// Indirect WREDs are addressed by their [index](Request::index), direct WREDs by the [match keys](Request::match_key) of the table entry.
+///
+/// # Example
+///
+/// ```
+/// use rbfrt::wred::{Request, WredSpec};
+///
+/// let req = Request::new("egress.queue_wred").index(0).spec(WredSpec {
+///     time_constant_ns: 1000.0,
+///     min_threshold_cells: 100,
+///     max_threshold_cells: 1000,
+///     max_probability: 0.1,
+/// });
+///
+/// assert!(req.get_spec().is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Request {
+    name: String,
+    index: Option<IndexType>,
+    match_keys: HashMap<String, MatchValue>,
+    spec: Option<WredSpec>,
+    pipe: Option<u32>,
+    from_hw: bool,
+}
+
+impl Request {
+    /// Creates a new [Request] for the WRED with the given `name`.
+    ///
+    /// For direct WREDs, `name` is the name of the match-action table.
+    pub fn new(name: &str) -> Request {
+        Request {
+            name: name.to_owned(),
+            index: None,
+            match_keys: HashMap::new(),
+            spec: None,
+            pipe: None,
+            from_hw: false,
+        }
+    }
+
+    /// Returns a new [Request] with the `index` of an indirect WRED.
+    pub fn index(self, index: IndexType) -> Request {
+        Request {
+            index: Some(index),
+            ..self
+        }
+    }
+
+    /// Adds a match key of the table entry of a direct WRED.
+    pub fn match_key(mut self, name: &str, match_value: MatchValue) -> Request {
+        self.match_keys.insert(name.to_owned(), match_value);
+        self
+    }
+
+    /// Sets the [WredSpec] to write.
+    pub fn spec(self, spec: WredSpec) -> Request {
+        Request {
+            spec: Some(spec),
+            ..self
+        }
+    }
+
+    /// Sets the pipe of the WRED.
+    pub fn pipe(self, pipe: u32) -> Request {
+        Request {
+            pipe: Some(pipe),
+            ..self
+        }
+    }
+
+    /// Sets if the WRED is read from the hardware instead of the software shadow of the switch.
+    pub fn from_hw(self, from_hw: bool) -> Request {
+        Request { from_hw, ..self }
+    }
+
+    /// Returns the WRED's `name` of the [Request].
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the [WredSpec] of the [Request], if set.
+    pub fn get_spec(&self) -> Option<&WredSpec> {
+        self.spec.as_ref()
+    }
+
+    /// Returns the `index` of the [Request].
+    pub fn get_index(&self) -> &Option<IndexType> {
+        &self.index
+    }
+
+    /// Returns the match keys of the [Request].
+    pub fn get_match_keys(&self) -> &HashMap<String, MatchValue> {
+        &self.match_keys
+    }
+
+    /// Returns the pipe of the [Request], if set.
+    pub fn get_pipe(&self) -> Option<u32> {
+        self.pipe
+    }
+
+    /// Returns if the WRED is read from the hardware.
+    pub fn is_from_hw(&self) -> bool {
+        self.from_hw
+    }
+}
+
+/// Decodes the float data field `name` of a [TableEntry].
+fn decode_float(entry: &TableEntry, name: &str) -> Result<f32, RBFRTError> {
+    f32::decode(field(entry, name)?.get_data().as_slice()).map_err(|e| {
+        RBFRTError::InvalidWredSpec {
+            message: format!("field {name} of {} is malformed: {e}", entry.table_name),
+        }
+    })
+}
+
+/// Returns the data field `name` of a [TableEntry].
+fn field<'a>(entry: &'a TableEntry, name: &str) -> Result<&'a ActionData, RBFRTError> {
+    entry
+        .get_action_data(name)
+        .map_err(|_| RBFRTError::InvalidWredSpec {
+            message: format!("entry of WRED {} has no field {name}", entry.table_name),
+        })
+}
@@ -0,0 +1,119 @@
+use rbfrt::error::RBFRTError;
+use rbfrt::table::MatchValue;
+use rbfrt::{counter, table, SwitchConnection};
+
+const CONFIG_FILE: &str = "example.conf";
+
+#[tokio::test]
+async fn test_read_counter() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    let req = counter::Request::new("ingress.port_counter")
+        .index(5)
+        .sync(true);
+
+    let counter = switch.get_counter_entry(req).await?;
+
+    // we queried a single counter entry
+    assert_eq!(counter.entries().len(), 1);
+
+    let entry = counter.get(5).unwrap();
+
+    assert_eq!(entry.get_index(), Some(5));
+    assert!(entry.bytes().is_some() || entry.packets().is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_reset_counter() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    let req = counter::Request::new("ingress.port_counter").index(5);
+
+    switch.reset_counter_entry(req.clone()).await?;
+
+    let counter = switch.get_counter_entry(req.sync(true)).await?;
+
+    let entry = counter.get(5).unwrap();
+
+    assert_eq!(entry.bytes().unwrap_or_default(), 0);
+    assert_eq!(entry.packets().unwrap_or_default(), 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_read_mixed_counters() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    let result = switch
+        .get_counter_entries(vec![
+            counter::Request::new("ingress.port_counter").index(1),
+            counter::Request::new("egress.port_counter").index(1),
+        ])
+        .await;
+
+    assert!(matches!(result, Err(RBFRTError::MixedRequestNames { .. })));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_reset_direct_counter() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    switch.clear_table("ingress.counter_forward").await?;
+
+    let req = table::Request::new("ingress.counter_forward")
+        .match_key("ig_intr_md.ingress_port", MatchValue::exact(10))
+        .action("ingress.do_forward")
+        .action_data("e_port", 20);
+
+    switch.write_table_entry(req).await?;
+
+    switch
+        .reset_counter_entry(
+            counter::Request::new("ingress.counter_forward")
+                .match_key("ig_intr_md.ingress_port", MatchValue::exact(10)),
+        )
+        .await?;
+
+    // the reset keeps the action of the entry
+    let entries = switch
+        .get_table_entries(
+            table::Request::new("ingress.counter_forward")
+                .match_key("ig_intr_md.ingress_port", MatchValue::exact(10)),
+        )
+        .await?;
+
+    let entry = entries.first().unwrap();
+
+    assert_eq!(entry.get_action_name(), "ingress.do_forward");
+    assert_eq!(entry.get_action_data("e_port")?.as_u32(), 20);
+    assert_eq!(entry.get_action_data("$COUNTER_SPEC_PKTS")?.as_u32(), 0);
+
+    switch.clear_table("ingress.counter_forward").await?;
+
+    Ok(())
+}
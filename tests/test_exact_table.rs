@@ -1,5 +1,12 @@
-use rbfrt::table::{MatchValue, ToBytes};
+use rbfrt::error::RBFRTError;
+use rbfrt::object::ObjectName;
+use rbfrt::selector::{ActionMember, SelectorGroup};
+use rbfrt::table::{
+    Atomicity, Direction, DynHashing, DynKeyMask, EntryHitState, EntryScope, IdleTable,
+    IdleTableMode, MatchValue, PreDeviceConfig, ScopeMode, TableOperation, ToBytes, Transaction,
+};
 use rbfrt::{table, SwitchConnection};
+use tokio_stream::StreamExt;
 
 const CONFIG_FILE: &str = "example.conf";
 
@@ -121,3 +128,1003 @@ async fn test_delete_entry() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_upsert_entry() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    switch.clear_table("ingress.exact_forward").await?;
+
+    // entry does not exist, upsert inserts it
+    let req = table::Request::new("ingress.exact_forward")
+        .match_key("ig_intr_md.ingress_port", MatchValue::exact(10))
+        .action("ingress.do_forward")
+        .action_data("e_port", 20);
+
+    switch.upsert_table_entry(req.clone()).await?;
+
+    let entries = switch.get_table_entries(req.clone()).await?;
+
+    assert_eq!(entries.len(), 1);
+
+    let val = entries.first().unwrap().get_action_data("e_port")?.as_u32();
+
+    assert_eq!(val, 20);
+
+    // entry exists, upsert modifies it
+    let update = table::Request::new("ingress.exact_forward")
+        .match_key("ig_intr_md.ingress_port", MatchValue::exact(10))
+        .action("ingress.do_forward")
+        .action_data("e_port", 25);
+
+    switch.upsert_table_entry(update).await?;
+
+    let entries = switch.get_table_entries(req).await?;
+
+    assert_eq!(entries.len(), 1);
+
+    let val = entries.first().unwrap().get_action_data("e_port")?.as_u32();
+
+    assert_eq!(val, 25);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_write_entries_rollback() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    switch.clear_table("ingress.exact_forward").await?;
+
+    let existing = table::Request::new("ingress.exact_forward")
+        .match_key("ig_intr_md.ingress_port", MatchValue::exact(10))
+        .action("ingress.do_forward")
+        .action_data("e_port", 20);
+
+    switch.write_table_entry(existing.clone()).await?;
+
+    // second request fails as the key already exists
+    let batch = vec![
+        table::Request::new("ingress.exact_forward")
+            .match_key("ig_intr_md.ingress_port", MatchValue::exact(11))
+            .action("ingress.do_forward")
+            .action_data("e_port", 21),
+        existing,
+    ];
+
+    let res = switch
+        .write_table_entries_atomic(batch, Atomicity::RollbackOnError)
+        .await;
+
+    assert!(res.is_err());
+
+    // first request of the batch was rolled back
+    let entries = switch
+        .get_table_entries(table::Request::new("ingress.exact_forward"))
+        .await?;
+
+    assert_eq!(entries.len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transaction() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    switch.clear_table("ingress.exact_forward").await?;
+
+    let req = table::Request::new("ingress.exact_forward")
+        .match_key("ig_intr_md.ingress_port", MatchValue::exact(10))
+        .action("ingress.do_forward")
+        .action_data("e_port", 20);
+
+    switch.write_table_entry(req.clone()).await?;
+
+    // move the entry from port 10 to port 11 and insert a new one for port 12
+    let transaction = Transaction::new()
+        .delete(req)
+        .insert(
+            table::Request::new("ingress.exact_forward")
+                .match_key("ig_intr_md.ingress_port", MatchValue::exact(11))
+                .action("ingress.do_forward")
+                .action_data("e_port", 20),
+        )
+        .upsert(
+            table::Request::new("ingress.exact_forward")
+                .match_key("ig_intr_md.ingress_port", MatchValue::exact(12))
+                .action("ingress.do_forward")
+                .action_data("e_port", 21),
+        );
+
+    switch.commit_transaction(transaction).await?;
+
+    let entries = switch
+        .get_table_entries(table::Request::new("ingress.exact_forward"))
+        .await?;
+
+    assert_eq!(entries.len(), 2);
+    assert!(entries.iter().all(|e| e
+        .get_key("ig_intr_md.ingress_port")
+        .unwrap()
+        .get_exact_value()
+        .to_u32()
+        != 10));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_large_transaction() -> Result<(), Box<dyn std::error::Error>> {
+    // batches of this size are split into multiple write requests
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .batch_message_size(2048)
+        .connect()
+        .await?;
+
+    switch.clear_table("ingress.exact_forward").await?;
+
+    let transaction = (0..100).fold(Transaction::new(), |t, port| {
+        t.insert(
+            table::Request::new("ingress.exact_forward")
+                .match_key("ig_intr_md.ingress_port", MatchValue::exact(port))
+                .action("ingress.do_forward")
+                .action_data("e_port", 20),
+        )
+    });
+
+    // the transaction is still committed as a single write request
+    switch.commit_transaction(transaction).await?;
+
+    let entries = switch
+        .get_table_entries(table::Request::new("ingress.exact_forward"))
+        .await?;
+
+    assert_eq!(entries.len(), 100);
+
+    switch.clear_table("ingress.exact_forward").await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_write_entries_batch_error() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    switch.clear_table("ingress.exact_forward").await?;
+
+    let existing = table::Request::new("ingress.exact_forward")
+        .match_key("ig_intr_md.ingress_port", MatchValue::exact(10))
+        .action("ingress.do_forward")
+        .action_data("e_port", 20);
+
+    switch.write_table_entry(existing.clone()).await?;
+
+    let batch = vec![
+        table::Request::new("ingress.exact_forward")
+            .match_key("ig_intr_md.ingress_port", MatchValue::exact(11))
+            .action("ingress.do_forward")
+            .action_data("e_port", 21),
+        existing,
+    ];
+
+    match switch.write_table_entries(batch).await {
+        Err(RBFRTError::BatchError { failures, .. }) => {
+            assert_eq!(failures.len(), 1);
+
+            let failure = failures.first().unwrap();
+
+            assert_eq!(failure.index, 1);
+            assert_eq!(failure.table_name, "ingress.exact_forward");
+        }
+        res => panic!("Expected batch error, got {res:?}"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_typed_errors() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    switch.clear_table("ingress.exact_forward").await?;
+
+    let req = table::Request::new("ingress.exact_forward")
+        .match_key("ig_intr_md.ingress_port", MatchValue::exact(10))
+        .action("ingress.do_forward")
+        .action_data("e_port", 20);
+
+    // entry does not exist yet
+    let res = switch.update_table_entry(req.clone()).await;
+
+    assert!(matches!(res, Err(RBFRTError::EntryNotFound { .. })));
+
+    switch.write_table_entry(req.clone()).await?;
+
+    // entry exists already
+    let res = switch.write_table_entry(req).await;
+
+    match res {
+        Err(e) => {
+            assert!(matches!(e, RBFRTError::EntryAlreadyExists { .. }));
+            assert!(!e.is_retryable());
+        }
+        Ok(_) => panic!("Expected an error."),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_write_entries_chunked() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .batch_message_size(2048)
+        .batch_concurrency(2)
+        .connect()
+        .await?;
+
+    switch.clear_table("ingress.exact_forward").await?;
+
+    let requests: Vec<table::Request> = (0..100u32)
+        .map(|port| {
+            table::Request::new("ingress.exact_forward")
+                .match_key("ig_intr_md.ingress_port", MatchValue::exact(port))
+                .action("ingress.do_forward")
+                .action_data("e_port", 20)
+        })
+        .collect();
+
+    switch.write_table_entries(requests).await?;
+
+    let entries = switch
+        .get_table_entries(table::Request::new("ingress.exact_forward"))
+        .await?;
+
+    assert_eq!(entries.len(), 100);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stream_entries() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    switch.clear_table("ingress.exact_forward").await?;
+
+    let requests: Vec<table::Request> = (0..100u32)
+        .map(|port| {
+            table::Request::new("ingress.exact_forward")
+                .match_key("ig_intr_md.ingress_port", MatchValue::exact(port))
+                .action("ingress.do_forward")
+                .action_data("e_port", 20)
+        })
+        .collect();
+
+    switch.write_table_entries(requests).await?;
+
+    let mut stream = switch
+        .stream_table_entries(table::Request::new("ingress.exact_forward"))
+        .await?;
+
+    let mut count = 0;
+
+    while let Some(entry) = stream.next().await {
+        assert_eq!(entry?.get_action_data("e_port")?.as_u32(), 20);
+        count += 1;
+    }
+
+    assert_eq!(count, 100);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_read_key_only() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    switch.clear_table("ingress.exact_forward").await?;
+
+    let req = table::Request::new("ingress.exact_forward")
+        .match_key("ig_intr_md.ingress_port", MatchValue::exact(10))
+        .action("ingress.do_forward")
+        .action_data("e_port", 20);
+
+    switch.write_table_entry(req).await?;
+
+    let read_req = table::Request::new("ingress.exact_forward")
+        .key_only(true)
+        .from_hw(true);
+
+    let entries = switch.get_table_entries(read_req).await?;
+
+    assert_eq!(entries.len(), 1);
+
+    let entry = entries.first().unwrap();
+
+    assert_eq!(
+        entry
+            .get_key("ig_intr_md.ingress_port")?
+            .get_exact_value()
+            .to_u32(),
+        10
+    );
+    assert!(entry.action_data.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_table_usage() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    switch.clear_table("ingress.exact_forward").await?;
+
+    let req = table::Request::new("ingress.exact_forward")
+        .match_key("ig_intr_md.ingress_port", MatchValue::exact(10))
+        .action("ingress.do_forward")
+        .action_data("e_port", 20);
+
+    switch.write_table_entry(req).await?;
+
+    let usage = switch.get_table_usage("ingress.exact_forward").await?;
+
+    assert_eq!(usage.usage, 1);
+    assert!(usage.size >= usage.usage);
+
+    let report = switch.capacity_report().await?;
+
+    assert!(report
+        .iter()
+        .any(|u| u.table_name == "ingress.exact_forward"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_default_entry() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    let req = table::Request::new("ingress.exact_forward")
+        .action("ingress.do_forward")
+        .action_data("e_port", 20);
+
+    // the default entry has no match keys
+    let result = switch
+        .set_default_entry(
+            req.clone()
+                .match_key("ig_intr_md.ingress_port", MatchValue::exact(10)),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(RBFRTError::DefaultEntryMatchKeys { .. })
+    ));
+
+    switch.set_default_entry(req).await?;
+
+    let entry = switch.get_default_entry("ingress.exact_forward").await?;
+
+    assert!(entry.default_entry);
+    assert_eq!(entry.get_action_name(), "ingress.do_forward");
+    assert_eq!(entry.get_action_data("e_port")?.as_u32(), 20);
+
+    switch.reset_default_entry("ingress.exact_forward").await?;
+
+    let entry = switch.get_default_entry("ingress.exact_forward").await?;
+
+    assert_ne!(entry.get_action_name(), "ingress.do_forward");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_entry_handle() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    switch.clear_table("ingress.exact_forward").await?;
+
+    let req = table::Request::new("ingress.exact_forward")
+        .match_key("ig_intr_md.ingress_port", MatchValue::exact(10))
+        .action("ingress.do_forward")
+        .action_data("e_port", 20);
+
+    switch.write_table_entry(req.clone()).await?;
+
+    let handle = switch.get_entry_handle(req).await?;
+
+    // modify by handle
+    let update = table::Request::new("ingress.exact_forward")
+        .handle(handle)
+        .action("ingress.do_forward")
+        .action_data("e_port", 30);
+
+    switch.update_table_entry(update).await?;
+
+    // read by handle
+    let entries = switch
+        .get_table_entries(table::Request::new("ingress.exact_forward").handle(handle))
+        .await?;
+
+    assert_eq!(entries.len(), 1);
+    assert_eq!(
+        entries.first().unwrap().get_action_data("e_port")?.as_u32(),
+        30
+    );
+
+    // delete by handle
+    switch
+        .delete_table_entry(table::Request::new("ingress.exact_forward").handle(handle))
+        .await?;
+
+    let entries = switch
+        .get_table_entries(
+            table::Request::new("ingress.exact_forward")
+                .match_key("ig_intr_md.ingress_port", MatchValue::exact(10)),
+        )
+        .await?;
+
+    assert_eq!(entries.len(), 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resolve_object_id() -> Result<(), Box<dyn std::error::Error>> {
+    // connecting verifies the cached schema
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .verify_schema(true)
+        .connect()
+        .await?;
+
+    let ids = switch
+        .resolve_object_ids(vec![
+            ObjectName::table("pipe.ingress.exact_forward"),
+            ObjectName::action("pipe.ingress.exact_forward", "ingress.do_forward"),
+        ])
+        .await?;
+
+    assert_eq!(ids.len(), 2);
+    assert_ne!(ids[0], ids[1]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_table_schema() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    let keys = switch.get_table_key_fields("ingress.exact_forward")?;
+
+    assert_eq!(keys, vec!["ig_intr_md.ingress_port".to_owned()]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_byte_count_adj() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    for name in ["ingress.port_counter", "ingress.port_meter"] {
+        switch.set_byte_count_adj(name, -4).await?;
+
+        assert_eq!(switch.get_byte_count_adj(name).await?, -4);
+
+        switch.set_byte_count_adj(name, 0).await?;
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_byte_count_adj_not_supported() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    // the table has neither a direct counter nor a direct meter
+    let result = switch.set_byte_count_adj("ingress.exact_forward", -4).await;
+
+    assert!(matches!(
+        result,
+        Err(RBFRTError::AttributeNotSupported { .. })
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_entry_scope() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    switch.clear_table("ingress.exact_forward").await?;
+
+    let req = table::Request::new("ingress.exact_forward")
+        .match_key("ig_intr_md.ingress_port", MatchValue::exact(1))
+        .action("ingress.do_forward")
+        .action_data("e_port", 2)
+        .pipe(0);
+
+    // a symmetric table rejects requests to a single pipe
+    switch
+        .set_entry_scope("ingress.exact_forward", EntryScope::new())
+        .await?;
+
+    let scope = switch.get_entry_scope("ingress.exact_forward").await?;
+
+    assert!(!scope.is_pipe_asymmetric());
+
+    let result = switch.write_table_entry(req.clone()).await;
+
+    assert!(matches!(
+        result,
+        Err(RBFRTError::SymmetricTable { pipe: 0, .. })
+    ));
+
+    // reads are checked as well
+    let result = switch
+        .get_table_entries(table::Request::new("ingress.exact_forward").pipe(0))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(RBFRTError::SymmetricTable { pipe: 0, .. })
+    ));
+
+    // requests to a single gress of a gress-symmetric table are rejected
+    let result = switch
+        .get_table_entries(
+            table::Request::new("ingress.exact_forward").direction(Direction::Ingress),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(RBFRTError::GressSymmetricTable { .. })
+    ));
+
+    // the default pipe of the connection applies to all requests
+    let pipe_switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(2)
+        .config(CONFIG_FILE)
+        .default_pipe(0)
+        .connect()
+        .await?;
+
+    let result = pipe_switch
+        .write_table_entry(
+            table::Request::new("ingress.exact_forward")
+                .match_key("ig_intr_md.ingress_port", MatchValue::exact(1))
+                .action("ingress.do_forward")
+                .action_data("e_port", 2),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(RBFRTError::SymmetricTable { pipe: 0, .. })
+    ));
+
+    switch
+        .set_entry_scope(
+            "ingress.exact_forward",
+            EntryScope::new().pipe_scope(ScopeMode::Single),
+        )
+        .await?;
+
+    let scope = switch.get_entry_scope("ingress.exact_forward").await?;
+
+    assert_eq!(scope.get_pipe_scope(), ScopeMode::Single);
+
+    switch.write_table_entry(req).await?;
+
+    switch.clear_table("ingress.exact_forward").await?;
+    switch
+        .set_entry_scope("ingress.exact_forward", EntryScope::new())
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dyn_key_mask_and_hashing() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    let mask = DynKeyMask::new().mask("hdr.ipv4.dst_addr", vec![255u8, 255, 255, 0]);
+
+    switch
+        .set_dyn_key_mask("ingress.dyn_forward", mask.clone())
+        .await?;
+
+    assert_eq!(switch.get_dyn_key_mask("ingress.dyn_forward").await?, mask);
+
+    // the exact table does not declare the dynamic key mask attribute
+    let result = switch
+        .set_dyn_key_mask("ingress.exact_forward", mask.clone())
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(RBFRTError::AttributeNotSupported { .. })
+    ));
+
+    let algorithms = switch.get_hash_algorithms("ingress.hash")?;
+
+    assert!(algorithms.len() > 1);
+
+    let default = switch.get_dyn_hashing("ingress.hash").await?;
+
+    // write an algorithm other than the default one by name
+    let algorithm = algorithms
+        .iter()
+        .find(|a| Some(a.as_str()) != default.get_algorithm())
+        .unwrap();
+
+    switch
+        .set_dyn_hashing("ingress.hash", DynHashing::new(algorithm, 42))
+        .await?;
+
+    let read = switch.get_dyn_hashing("ingress.hash").await?;
+
+    assert_eq!(read.get_algorithm(), Some(algorithm.as_str()));
+    assert_ne!(read.get_handle(), default.get_handle());
+    assert_eq!(read.get_seed(), 42);
+
+    // restore the default algorithm by its handle
+    switch
+        .set_dyn_hashing(
+            "ingress.hash",
+            DynHashing::from_handle(default.get_handle().unwrap(), default.get_seed()),
+        )
+        .await?;
+
+    assert_eq!(switch.get_dyn_hashing("ingress.hash").await?, default);
+
+    let result = switch
+        .set_dyn_hashing("ingress.hash", DynHashing::new("unknown", 0))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(RBFRTError::UnknownHashAlgorithm { .. })
+    ));
+
+    let result = switch.get_dyn_hashing("ingress.exact_forward").await;
+
+    assert!(matches!(
+        result,
+        Err(RBFRTError::AttributeNotSupported { .. })
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_idle_table() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    switch.clear_table("ingress.exact_forward").await?;
+
+    let req = table::Request::new("ingress.exact_forward")
+        .match_key("ig_intr_md.ingress_port", MatchValue::exact(10))
+        .action("ingress.do_forward")
+        .action_data("e_port", 20);
+
+    let read_req = table::Request::new("ingress.exact_forward")
+        .match_key("ig_intr_md.ingress_port", MatchValue::exact(10));
+
+    // poll mode: the hit state is refreshed on request
+    switch
+        .enable_idle_table("ingress.exact_forward", IdleTable::new(IdleTableMode::Poll))
+        .await?;
+
+    let idle_table = switch.get_idle_table("ingress.exact_forward").await?;
+
+    assert!(idle_table.is_enabled());
+    assert_eq!(idle_table.get_mode(), IdleTableMode::Poll);
+
+    switch.write_table_entry(req.clone()).await?;
+
+    switch
+        .execute_operation(
+            table::Request::new("ingress.exact_forward").operation(TableOperation::UpdateHitState),
+        )
+        .await?;
+
+    let entries = switch.get_table_entries(read_req.clone()).await?;
+
+    assert_eq!(
+        entries.first().unwrap().get_entry_hit_state(),
+        Some(EntryHitState::Idle)
+    );
+
+    switch.clear_table("ingress.exact_forward").await?;
+
+    // notify mode: entries carry a TTL
+    switch
+        .enable_idle_table(
+            "ingress.exact_forward",
+            IdleTable::new(IdleTableMode::Notify)
+                .ttl_query_interval(1000)
+                .min_ttl(1000)
+                .max_ttl(60000),
+        )
+        .await?;
+
+    switch.write_table_entry(req.entry_ttl(5000)).await?;
+
+    let entries = switch.get_table_entries(read_req).await?;
+    let ttl = entries.first().unwrap().get_entry_ttl();
+
+    assert!(ttl.is_some_and(|ttl| ttl <= 5000));
+
+    switch.clear_table("ingress.exact_forward").await?;
+    switch.disable_idle_table("ingress.exact_forward").await?;
+
+    assert!(!switch
+        .get_idle_table("ingress.exact_forward")
+        .await?
+        .is_enabled());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_add_to_and_delete_from_entry() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    switch.clear_table("ingress.ecmp").await?;
+    switch.clear_selector("ingress.ecmp_selector").await?;
+
+    switch
+        .add_action_members(
+            "ingress.ecmp_profile",
+            vec![
+                ActionMember::new(1, "ingress.set_port").action_data("port", 1),
+                ActionMember::new(2, "ingress.set_port").action_data("port", 2),
+            ],
+        )
+        .await?;
+
+    switch
+        .add_selector_group("ingress.ecmp_selector", SelectorGroup::new(10, 4))
+        .await?;
+
+    // append a single member to the group
+    let req = table::Request::new("ingress.ecmp_selector")
+        .match_key("$SELECTOR_GROUP_ID", MatchValue::exact(10))
+        .action_data_repeated("$ACTION_MEMBER_ID", vec![2u32])
+        .action_data_repeated("$ACTION_MEMBER_STATUS", vec![true]);
+
+    switch.add_to_table_entry(req.clone()).await?;
+
+    let group = switch
+        .get_selector_group("ingress.ecmp_selector", 10)
+        .await?;
+
+    assert_eq!(group.get_members().len(), 1);
+    assert_eq!(group.get_members().first().unwrap().member_id, 2);
+
+    switch.delete_from_table_entry(req).await?;
+
+    let group = switch
+        .get_selector_group("ingress.ecmp_selector", 10)
+        .await?;
+
+    assert!(group.get_members().is_empty());
+
+    // the key of the entry must be present
+    let result = switch
+        .add_to_table_entry(
+            table::Request::new("ingress.ecmp_selector")
+                .match_key("$SELECTOR_GROUP_ID", MatchValue::exact(11))
+                .action_data_repeated("$ACTION_MEMBER_ID", vec![1u32])
+                .action_data_repeated("$ACTION_MEMBER_STATUS", vec![true]),
+        )
+        .await;
+
+    assert!(result.is_err());
+
+    switch.clear_selector("ingress.ecmp_selector").await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pre_device_config() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    let original = switch.get_pre_device_config().await?;
+
+    switch
+        .set_pre_device_config(PreDeviceConfig::new().global_rid(42).fast_failover(true))
+        .await?;
+
+    let config = switch.get_pre_device_config().await?;
+
+    assert_eq!(config.get_global_rid(), Some(42));
+    assert_eq!(config.get_fast_failover(), Some(true));
+    // settings that are not set remain unchanged
+    assert_eq!(config.get_port_protection(), original.get_port_protection());
+
+    switch
+        .set_pre_device_config(
+            PreDeviceConfig::new()
+                .global_rid(original.get_global_rid().unwrap_or_default())
+                .fast_failover(original.get_fast_failover().unwrap_or_default()),
+        )
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_default_target() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    switch.clear_table("ingress.exact_forward").await?;
+    switch
+        .set_entry_scope(
+            "ingress.exact_forward",
+            EntryScope::new().pipe_scope(ScopeMode::Single),
+        )
+        .await?;
+
+    // all requests of this connection are addressed to pipe 0
+    let pipe_switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(2)
+        .config(CONFIG_FILE)
+        .default_pipe(0)
+        .default_direction(Direction::All)
+        .default_parser(0xff)
+        .connect()
+        .await?;
+
+    let req = table::Request::new("ingress.exact_forward")
+        .match_key("ig_intr_md.ingress_port", MatchValue::exact(10))
+        .action("ingress.do_forward")
+        .action_data("e_port", 20);
+
+    pipe_switch.write_table_entry(req).await?;
+
+    let read_req = table::Request::new("ingress.exact_forward")
+        .match_key("ig_intr_md.ingress_port", MatchValue::exact(10));
+
+    let entries = pipe_switch.get_table_entries(read_req.clone()).await?;
+
+    assert_eq!(entries.len(), 1);
+    assert_eq!(
+        entries.first().unwrap().get_action_data("e_port")?.as_u32(),
+        20
+    );
+
+    // the target of a request overrides the default target
+    let entries = switch
+        .get_table_entries(
+            read_req
+                .clone()
+                .pipe(0)
+                .direction(Direction::All)
+                .parser(0xff),
+        )
+        .await?;
+
+    assert_eq!(entries.len(), 1);
+
+    let entries = switch
+        .get_table_entries(table::Request::new("ingress.exact_forward").pipe(1))
+        .await?;
+
+    assert!(entries.is_empty());
+
+    switch.clear_table("ingress.exact_forward").await?;
+    switch
+        .set_entry_scope("ingress.exact_forward", EntryScope::new())
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use rbfrt::error::RBFRTError;
+use rbfrt::lpf::{LpfSpec, LpfType, Request};
+use rbfrt::table::MatchValue;
+use rbfrt::{table, SwitchConnection};
+
+const CONFIG_FILE: &str = "example.conf";
+
+#[tokio::test]
+async fn test_write_indexed_lpf() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    let spec = LpfSpec {
+        lpf_type: LpfType::Sample,
+        gain_time_constant_ns: 1000.0,
+        decay_time_constant_ns: 2000.0,
+        output_scale_down_factor: 2,
+    };
+
+    let req = Request::new("ingress.lpf").index(5).spec(spec);
+
+    switch.write_lpf_entry(req).await?;
+
+    let lpf = switch
+        .get_lpf_entry(Request::new("ingress.lpf").index(5))
+        .await?;
+
+    // we queried a single filter entry
+    assert_eq!(lpf.entries().len(), 1);
+    assert_eq!(*lpf.get(5).unwrap().get_spec(), spec);
+
+    switch.reset_table("ingress.lpf").await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_write_direct_lpf() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    let spec = LpfSpec {
+        lpf_type: LpfType::Sample,
+        gain_time_constant_ns: 1000.0,
+        decay_time_constant_ns: 2000.0,
+        output_scale_down_factor: 2,
+    };
+
+    switch.clear_table("ingress.lpf_forward").await?;
+
+    let entry = table::Request::new("ingress.lpf_forward")
+        .match_key("ig_intr_md.ingress_port", MatchValue::exact(1))
+        .action("ingress.do_forward")
+        .action_data("e_port", 2);
+
+    switch.write_table_entry(entry).await?;
+
+    let req = Request::new("ingress.lpf_forward")
+        .match_key("ig_intr_md.ingress_port", MatchValue::exact(1))
+        .spec(spec);
+
+    switch.write_lpf_entry(req).await?;
+
+    let lpf = switch
+        .get_lpf_entry(
+            Request::new("ingress.lpf_forward")
+                .match_key("ig_intr_md.ingress_port", MatchValue::exact(1)),
+        )
+        .await?;
+
+    let lpf_entry = lpf.entries().first().unwrap();
+
+    assert_eq!(lpf_entry.get_index(), None);
+    assert_eq!(*lpf_entry.get_spec(), spec);
+
+    switch.clear_table("ingress.lpf_forward").await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_invalid_lpf_spec() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    let spec = LpfSpec {
+        lpf_type: LpfType::Sample,
+        gain_time_constant_ns: 1000.0,
+        decay_time_constant_ns: 2000.0,
+        output_scale_down_factor: 2,
+    };
+
+    let req = Request::new("ingress.lpf").index(5).spec(LpfSpec {
+        gain_time_constant_ns: -1.0,
+        ..spec
+    });
+
+    let res = switch.write_lpf_entry(req).await;
+
+    assert!(matches!(res, Err(RBFRTError::InvalidLpfSpec { .. })));
+
+    Ok(())
+}
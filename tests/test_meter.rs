@@ -0,0 +1,147 @@
+use rbfrt::error::RBFRTError;
+use rbfrt::meter::{MeterSpec, Rate};
+use rbfrt::table::MatchValue;
+use rbfrt::{meter, table, SwitchConnection};
+
+const CONFIG_FILE: &str = "example.conf";
+
+#[tokio::test]
+async fn test_write_meter() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    let spec = MeterSpec::bytes(Rate::Mbps(100), Rate::Mbps(200), 1000, 2000)?;
+
+    switch
+        .write_meter_entry(
+            meter::Request::new("ingress.port_meter")
+                .index(5)
+                .spec(spec),
+        )
+        .await?;
+
+    let meter = switch
+        .get_meter_entry(meter::Request::new("ingress.port_meter").index(5))
+        .await?;
+
+    let entry = meter.get(5).unwrap();
+
+    // the switch may round the rates to its granularity
+    assert!(!entry.get_spec().is_packet_based());
+    assert!(entry.get_spec().cir().to_kbps().unwrap() >= 100_000);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_write_invalid_meter_spec() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    // the meter is byte-based
+    let result = switch
+        .write_meter_entry(
+            meter::Request::new("ingress.port_meter")
+                .index(5)
+                .spec(MeterSpec::packets(100, 200, 10, 20)),
+        )
+        .await;
+
+    assert!(matches!(result, Err(RBFRTError::InvalidMeterSpec { .. })));
+
+    let result = switch
+        .write_meter_entry(meter::Request::new("ingress.port_meter").index(5))
+        .await;
+
+    assert!(matches!(result, Err(RBFRTError::InvalidMeterSpec { .. })));
+
+    let result = MeterSpec::bytes(Rate::Mbps(u64::MAX), Rate::Mbps(200), 1000, 2000);
+
+    assert!(matches!(result, Err(RBFRTError::InvalidMeterSpec { .. })));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_read_mixed_meters() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    let result = switch
+        .get_meter_entries(vec![
+            meter::Request::new("ingress.port_meter").index(1),
+            meter::Request::new("egress.port_meter").index(1),
+        ])
+        .await;
+
+    assert!(matches!(result, Err(RBFRTError::MixedRequestNames { .. })));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_write_direct_meter() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    switch.clear_table("ingress.meter_forward").await?;
+
+    let req = table::Request::new("ingress.meter_forward")
+        .match_key("ig_intr_md.ingress_port", MatchValue::exact(10))
+        .action("ingress.do_forward")
+        .action_data("e_port", 20);
+
+    switch.write_table_entry(req).await?;
+
+    let spec = MeterSpec::bytes(Rate::Mbps(100), Rate::Mbps(200), 1000, 2000)?;
+
+    switch
+        .write_meter_entry(
+            meter::Request::new("ingress.meter_forward")
+                .match_key("ig_intr_md.ingress_port", MatchValue::exact(10))
+                .spec(spec),
+        )
+        .await?;
+
+    // the meter spec write keeps the action of the entry
+    let entries = switch
+        .get_table_entries(
+            table::Request::new("ingress.meter_forward")
+                .match_key("ig_intr_md.ingress_port", MatchValue::exact(10)),
+        )
+        .await?;
+
+    let entry = entries.first().unwrap();
+
+    assert_eq!(entry.get_action_name(), "ingress.do_forward");
+    assert_eq!(entry.get_action_data("e_port")?.as_u32(), 20);
+
+    let meter = switch
+        .get_meter_entry(
+            meter::Request::new("ingress.meter_forward")
+                .match_key("ig_intr_md.ingress_port", MatchValue::exact(10)),
+        )
+        .await?;
+
+    assert_eq!(meter.entries().len(), 1);
+
+    switch.clear_table("ingress.meter_forward").await?;
+
+    Ok(())
+}
@@ -0,0 +1,207 @@
+use rbfrt::error::RBFRTError;
+use rbfrt::util::{MulticastGroup, MulticastLag, MulticastManager, MulticastNode};
+use rbfrt::SwitchConnection;
+use std::collections::HashSet;
+
+const CONFIG_FILE: &str = "example.conf";
+
+/// The switch may return the ports of a node in any order.
+fn port_set(ports: &[(u32, u8)]) -> HashSet<(u32, u8)> {
+    ports.iter().copied().collect()
+}
+
+#[tokio::test]
+async fn test_multicast_group() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    let mm = MulticastManager::new(&switch).await;
+
+    let group = MulticastGroup::new(10)
+        .node(MulticastNode::new(100, 1).port(1, 0).port(2, 0))
+        .node_with_l1_xid(MulticastNode::new(101, 2).port(3, 0), 7);
+
+    mm.add_group(&switch, &group).await?;
+
+    let read_group = mm.get_group(&switch, 10).await?;
+
+    assert_eq!(read_group.get_mgid(), 10);
+    assert_eq!(read_group.get_members().len(), 2);
+    assert_eq!(
+        port_set(&read_group.get_ports()),
+        port_set(&group.get_ports())
+    );
+
+    for (read, written) in read_group.get_members().iter().zip(group.get_members()) {
+        assert_eq!(read.node.get_id(), written.node.get_id());
+        assert_eq!(read.node.get_rid(), written.node.get_rid());
+        assert_eq!(read.l1_xid, written.l1_xid);
+        assert_eq!(
+            port_set(read.node.get_ports()),
+            port_set(written.node.get_ports())
+        );
+    }
+
+    mm.delete_group(&switch, 10).await?;
+
+    assert!(mm.get_group(&switch, 10).await.is_err());
+    assert!(mm.get_node(&switch, 100).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_multicast_shared_node() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    let mm = MulticastManager::new(&switch).await;
+
+    let shared = MulticastNode::new(200, 1).port(1, 0);
+
+    mm.add_group(
+        &switch,
+        &MulticastGroup::new(20)
+            .node(shared.clone())
+            .node(MulticastNode::new(201, 1).port(2, 0)),
+    )
+    .await?;
+    mm.add_group(&switch, &MulticastGroup::new(21).node(shared))
+        .await?;
+
+    // the shared node is still used by group 21
+    mm.delete_group(&switch, 20).await?;
+
+    assert!(mm.get_node(&switch, 200).await.is_ok());
+    assert!(mm.get_node(&switch, 201).await.is_err());
+
+    mm.delete_group(&switch, 21).await?;
+
+    assert!(mm.get_node(&switch, 200).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_multicast_node_conflict() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    let mm = MulticastManager::new(&switch).await;
+
+    mm.add_group(
+        &switch,
+        &MulticastGroup::new(30).node(MulticastNode::new(300, 1).port(1, 0)),
+    )
+    .await?;
+
+    // node 300 is used by group 30 with other ports
+    let result = mm
+        .add_group(
+            &switch,
+            &MulticastGroup::new(31).node(MulticastNode::new(300, 1).port(2, 0)),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(RBFRTError::MulticastIdInUse { id: 300, .. })
+    ));
+    assert_eq!(
+        port_set(mm.get_node(&switch, 300).await?.get_ports()),
+        port_set(&[(1, 0)])
+    );
+
+    // group 30 already exists, so the new node is rolled back
+    let result = mm
+        .add_group(
+            &switch,
+            &MulticastGroup::new(30).node(MulticastNode::new(301, 1).port(2, 0)),
+        )
+        .await;
+
+    assert!(result.is_err());
+    assert!(mm.get_node(&switch, 301).await.is_err());
+
+    mm.delete_group(&switch, 30).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_multicast_lag() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    let mm = MulticastManager::new(&switch).await;
+
+    let lag = MulticastLag::new(3).port(1, 0).port(2, 0);
+
+    mm.add_lag(&switch, &lag).await?;
+
+    let read_lag = mm.get_lag(&switch, 3).await?;
+
+    assert_eq!(read_lag.get_id(), 3);
+    assert_eq!(port_set(read_lag.get_ports()), port_set(lag.get_ports()));
+
+    // the LAG is in use with other ports
+    let result = mm.add_lag(&switch, &MulticastLag::new(3).port(3, 0)).await;
+
+    assert!(matches!(
+        result,
+        Err(RBFRTError::MulticastIdInUse { id: 3, .. })
+    ));
+
+    mm.delete_lag(&switch, 3).await?;
+
+    assert!(mm.get_lag(&switch, 3).await?.get_ports().is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_multicast_l2_exclusion() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    let mm = MulticastManager::new(&switch).await;
+
+    mm.add_l2_exclusion(&switch, 5, &[(1, 0), (3, 0)]).await?;
+
+    let ports = mm.get_l2_exclusion(&switch, 5).await?;
+
+    assert_eq!(port_set(&ports), port_set(&[(1, 0), (3, 0)]));
+
+    let result = mm.add_l2_exclusion(&switch, 5, &[(2, 0)]).await;
+
+    assert!(matches!(
+        result,
+        Err(RBFRTError::MulticastIdInUse { id: 5, .. })
+    ));
+
+    mm.delete_l2_exclusion(&switch, 5).await?;
+
+    assert!(mm.get_l2_exclusion(&switch, 5).await?.is_empty());
+
+    Ok(())
+}
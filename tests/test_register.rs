@@ -37,3 +37,37 @@ async fn test_write_entry() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_reset_register() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    let req = register::Request::new("ingress.p4tg.rx_seq")
+        .index(5)
+        .data("ingress.p4tg.rx_seq.f1", 20);
+
+    switch.write_register_entry(req.clone()).await?;
+
+    switch.reset_table("ingress.p4tg.rx_seq").await?;
+
+    let register = switch.get_register_entry(req).await?;
+
+    let reg_entry = register.get(5).unwrap();
+
+    assert_eq!(
+        reg_entry
+            .get("ingress.p4tg.rx_seq.f1")
+            .unwrap()
+            .first()
+            .unwrap()
+            .to_u32(),
+        0
+    );
+
+    Ok(())
+}
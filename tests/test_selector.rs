@@ -0,0 +1,213 @@
+use rbfrt::error::RBFRTError;
+use rbfrt::selector::{ActionMember, GroupMember, SelectorGroup};
+use rbfrt::table::MatchValue;
+use rbfrt::{table, SwitchConnection};
+
+const CONFIG_FILE: &str = "example.conf";
+
+const PROFILE: &str = "ingress.ecmp_profile";
+const SELECTOR: &str = "ingress.ecmp_selector";
+const MATCH_TABLE: &str = "ingress.ecmp";
+
+#[tokio::test]
+async fn test_action_members() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+    switch.clear_table(MATCH_TABLE).await?;
+    switch.clear_selector(SELECTOR).await?;
+
+    let members = vec![
+        ActionMember::new(1, "ingress.set_port").action_data("port", 1),
+        ActionMember::new(2, "ingress.set_port").action_data("port", 2),
+        ActionMember::new(3, "ingress.set_port").action_data("port", 3),
+    ];
+
+    switch.add_action_members(PROFILE, members).await?;
+
+    let read = switch.get_action_members(PROFILE).await?;
+
+    assert_eq!(read.len(), 3);
+
+    let member = read.iter().find(|m| m.get_id() == 2).unwrap();
+
+    assert_eq!(member.get_action_name(), "ingress.set_port");
+    assert_eq!(member.get_action_data_by_name("port").unwrap().as_u32(), 2);
+
+    switch.delete_action_members(PROFILE, vec![2]).await?;
+
+    let read = switch.get_action_members(PROFILE).await?;
+
+    assert_eq!(read.len(), 2);
+    assert!(read.iter().all(|m| m.get_id() != 2));
+
+    switch.clear_table(MATCH_TABLE).await?;
+    switch.clear_selector(SELECTOR).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_selector_group() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+    switch.clear_table(MATCH_TABLE).await?;
+    switch.clear_selector(SELECTOR).await?;
+
+    let members = vec![
+        ActionMember::new(1, "ingress.set_port").action_data("port", 1),
+        ActionMember::new(2, "ingress.set_port").action_data("port", 2),
+        ActionMember::new(3, "ingress.set_port").action_data("port", 3),
+    ];
+
+    switch.add_action_members(PROFILE, members).await?;
+
+    let group = SelectorGroup::new(10, 4)
+        .member(GroupMember::new(1))
+        .member(GroupMember::new(2));
+
+    switch.add_selector_group(SELECTOR, group.clone()).await?;
+
+    let read = switch.get_selector_group(SELECTOR, 10).await?;
+
+    assert_eq!(read.get_max_size(), 4);
+    assert_eq!(read.get_members().len(), 2);
+    assert_eq!(read.get_enabled_members().count(), 2);
+
+    // disabled members stay in the group
+    switch
+        .set_group_member_status(SELECTOR, 10, 2, false)
+        .await?;
+
+    let read = switch.get_selector_group(SELECTOR, 10).await?;
+
+    assert_eq!(read.get_members().len(), 2);
+    assert!(!read.get_member(2).unwrap().enabled);
+
+    let result = switch.set_group_member_status(SELECTOR, 10, 3, false).await;
+
+    assert!(matches!(
+        result,
+        Err(RBFRTError::UnknownGroupMember { member_id: 3, .. })
+    ));
+
+    switch
+        .update_selector_group(SELECTOR, group.member(GroupMember::new(3)))
+        .await?;
+
+    let groups = switch.get_selector_groups(SELECTOR).await?;
+
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].get_members().len(), 3);
+
+    switch.clear_table(MATCH_TABLE).await?;
+    switch.clear_selector(SELECTOR).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_weighted_selector_group() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+    switch.clear_table(MATCH_TABLE).await?;
+    switch.clear_selector(SELECTOR).await?;
+
+    let members = vec![
+        ActionMember::new(1, "ingress.set_port").action_data("port", 1),
+        ActionMember::new(2, "ingress.set_port").action_data("port", 2),
+        ActionMember::new(3, "ingress.set_port").action_data("port", 3),
+    ];
+
+    switch.add_action_members(PROFILE, members).await?;
+
+    let group = SelectorGroup::new(10, 8)
+        .member(GroupMember::new(1).weight(3))
+        .member(GroupMember::new(2));
+
+    match switch.add_selector_group(SELECTOR, group).await {
+        // the selector supports weights
+        Ok(()) => {
+            let read = switch.get_selector_group(SELECTOR, 10).await?;
+
+            assert_eq!(read.get_member(1).unwrap().weight, 3);
+            assert_eq!(read.get_member(2).unwrap().weight, 1);
+        }
+        Err(RBFRTError::AttributeNotSupported { attribute, .. }) => {
+            assert_eq!(attribute, "$ACTION_MEMBER_WEIGHT");
+        }
+        Err(e) => return Err(e.into()),
+    }
+
+    switch.clear_table(MATCH_TABLE).await?;
+    switch.clear_selector(SELECTOR).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_selector_delete_order() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+    switch.clear_table(MATCH_TABLE).await?;
+    switch.clear_selector(SELECTOR).await?;
+
+    let members = vec![
+        ActionMember::new(1, "ingress.set_port").action_data("port", 1),
+        ActionMember::new(2, "ingress.set_port").action_data("port", 2),
+        ActionMember::new(3, "ingress.set_port").action_data("port", 3),
+    ];
+
+    switch.add_action_members(PROFILE, members).await?;
+    switch
+        .add_selector_group(
+            SELECTOR,
+            SelectorGroup::new(10, 4)
+                .member(GroupMember::new(1))
+                .member(GroupMember::new(2)),
+        )
+        .await?;
+
+    // deleting a member removes it from the group first
+    switch.delete_action_members(PROFILE, vec![1]).await?;
+
+    let read = switch.get_selector_group(SELECTOR, 10).await?;
+
+    assert!(read.get_member(1).is_none());
+    assert!(read.get_member(2).is_some());
+
+    let entry = table::Request::new(MATCH_TABLE)
+        .match_key("hdr.ipv4.dst_addr", MatchValue::exact(0x0a000001))
+        .selector_group(10);
+
+    switch.write_table_entry(entry).await?;
+
+    // the group is still referenced and nothing is deleted
+    let result = switch.clear_selector(SELECTOR).await;
+
+    assert!(matches!(result, Err(RBFRTError::ObjectInUse { .. })));
+    assert_eq!(switch.get_selector_groups(SELECTOR).await?.len(), 1);
+
+    switch.clear_table(MATCH_TABLE).await?;
+    switch.clear_selector(SELECTOR).await?;
+
+    assert!(switch.get_selector_groups(SELECTOR).await?.is_empty());
+    assert!(switch.get_action_members(PROFILE).await?.is_empty());
+
+    Ok(())
+}
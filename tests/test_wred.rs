@@ -0,0 +1,129 @@
+use rbfrt::error::RBFRTError;
+use rbfrt::table::MatchValue;
+use rbfrt::wred::{Request, WredSpec};
+use rbfrt::{table, SwitchConnection};
+
+const CONFIG_FILE: &str = "example.conf";
+
+#[tokio::test]
+async fn test_write_indexed_wred() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    let spec = WredSpec {
+        time_constant_ns: 1000.0,
+        min_threshold_cells: 100,
+        max_threshold_cells: 1000,
+        max_probability: 0.5,
+    };
+
+    let req = Request::new("egress.wred").index(3).spec(spec);
+
+    switch.write_wred_entry(req).await?;
+
+    let wred = switch
+        .get_wred_entry(Request::new("egress.wred").index(3))
+        .await?;
+
+    // we queried a single WRED entry
+    assert_eq!(wred.entries().len(), 1);
+
+    let wred_spec = wred.get(3).unwrap().get_spec();
+
+    assert_eq!(wred_spec.min_threshold_cells, 100);
+    assert_eq!(wred_spec.max_threshold_cells, 1000);
+    assert_eq!(wred_spec.max_probability, 0.5);
+
+    switch.reset_table("egress.wred").await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_write_direct_wred() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    let spec = WredSpec {
+        time_constant_ns: 1000.0,
+        min_threshold_cells: 100,
+        max_threshold_cells: 1000,
+        max_probability: 0.5,
+    };
+
+    switch.clear_table("egress.wred_forward").await?;
+
+    let entry = table::Request::new("egress.wred_forward")
+        .match_key("eg_intr_md.egress_port", MatchValue::exact(2))
+        .action("egress.mark")
+        .action_data("dscp", 1);
+
+    switch.write_table_entry(entry).await?;
+
+    let req = Request::new("egress.wred_forward")
+        .match_key("eg_intr_md.egress_port", MatchValue::exact(2))
+        .spec(spec);
+
+    switch.write_wred_entry(req).await?;
+
+    let wred = switch
+        .get_wred_entry(
+            Request::new("egress.wred_forward")
+                .match_key("eg_intr_md.egress_port", MatchValue::exact(2)),
+        )
+        .await?;
+
+    let wred_entry = wred.entries().first().unwrap();
+
+    assert_eq!(wred_entry.get_index(), None);
+    assert_eq!(wred_entry.get_spec().min_threshold_cells, 100);
+
+    switch.clear_table("egress.wred_forward").await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_invalid_wred_spec() -> Result<(), Box<dyn std::error::Error>> {
+    let switch = SwitchConnection::builder("localhost", 50052)
+        .device_id(0)
+        .client_id(1)
+        .config(CONFIG_FILE)
+        .connect()
+        .await?;
+
+    let spec = WredSpec {
+        time_constant_ns: 1000.0,
+        min_threshold_cells: 100,
+        max_threshold_cells: 1000,
+        max_probability: 0.5,
+    };
+
+    let probability = Request::new("egress.wred").index(3).spec(WredSpec {
+        max_probability: 1.5,
+        ..spec
+    });
+
+    let res = switch.write_wred_entry(probability).await;
+
+    assert!(matches!(res, Err(RBFRTError::InvalidWredSpec { .. })));
+
+    let thresholds = Request::new("egress.wred").index(3).spec(WredSpec {
+        min_threshold_cells: 2000,
+        ..spec
+    });
+
+    let res = switch.write_wred_entry(thresholds).await;
+
+    assert!(matches!(res, Err(RBFRTError::InvalidWredSpec { .. })));
+
+    Ok(())
+}